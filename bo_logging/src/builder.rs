@@ -1,15 +1,38 @@
 use super::*;
 
-#[derive(Default)]
 pub struct Logger {
     current_color: RGB,
+    category: LogCategory,
+    source: Option<LogSource>,
     fragments: Vec<LogFragment>,
 }
 
+impl Default for Logger {
+    fn default() -> Self { Self::new() }
+}
+
 impl Logger {
-    pub fn new() -> Self { Logger { current_color: RGB::named(WHITE), fragments: Vec::new() } }
+    pub fn new() -> Self {
+        Logger {
+            current_color: RGB::named(WHITE),
+            category: LogCategory::System,
+            source: None,
+            fragments: Vec::new(),
+        }
+    }
 
-    pub fn log(self) { append_entry(self.fragments) }
+    pub fn log(self) { append_entry(self.category, self.source, self.fragments) }
+
+    pub fn category(mut self, category: LogCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Ties the message to the entity it's about, by its bits and name
+    pub fn source<T: ToString>(mut self, id: u64, name: T) -> Self {
+        self.source = Some(LogSource { id, name: name.to_string() });
+        self
+    }
 
     pub fn color(mut self, color: (u8, u8, u8)) -> Self {
         self.current_color = RGB::named(color);
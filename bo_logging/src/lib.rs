@@ -1,12 +1,15 @@
 use bracket_lib::prelude::*;
+use serde::Serialize;
 
 mod builder;
 mod events;
 mod logstore;
+mod sinks;
 
 pub use crate::builder::*;
 pub use crate::events::*;
 pub use crate::logstore::*;
+pub use crate::sinks::*;
 pub use crate::logstore::{clear_log, clone_log, print_log, restore_log};
 pub use builder::Logger;
 
@@ -15,3 +18,79 @@ pub struct LogFragment {
     pub color: RGB,
     pub text: String,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum LogCategory {
+    Combat,
+    Loot,
+    Status,
+    Dialogue,
+    System,
+}
+
+impl LogCategory {
+    pub const ALL: [LogCategory; 5] = [
+        LogCategory::Combat,
+        LogCategory::Loot,
+        LogCategory::Status,
+        LogCategory::Dialogue,
+        LogCategory::System,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogCategory::Combat => "Combat",
+            LogCategory::Loot => "Loot",
+            LogCategory::Status => "Status",
+            LogCategory::Dialogue => "Dialogue",
+            LogCategory::System => "System",
+        }
+    }
+}
+
+/// Who a message is about. The id is the entity's bits, so the log doesn't depend on the ECS.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LogSource {
+    pub id: u64,
+    pub name: String,
+}
+
+/// One line of the message log: what was said, when, by whom, and how many times in a row
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    pub turn: i32,
+    pub category: LogCategory,
+    pub source: Option<LogSource>,
+    pub count: u32,
+    pub fragments: Vec<LogFragment>,
+}
+
+impl LogEntry {
+    pub fn text(&self) -> String {
+        self.fragments.iter().map(|fragment| fragment.text.as_str()).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Shown after a message that was repeated, like "(x3)"
+    pub fn repeat_suffix(&self) -> Option<String> {
+        if self.count > 1 {
+            Some(format!("(x{})", self.count))
+        } else {
+            None
+        }
+    }
+
+    pub fn display_text(&self) -> String {
+        match self.repeat_suffix() {
+            Some(suffix) => format!("{} {}", self.text(), suffix),
+            None => self.text(),
+        }
+    }
+
+    /// Whether another entry says the same thing, so the two can be folded together
+    fn repeats(&self, other: &LogEntry) -> bool {
+        self.category == other.category
+            && self.source == other.source
+            && self.fragments.len() == other.fragments.len()
+            && self.fragments.iter().zip(other.fragments.iter()).all(|(a, b)| a.text == b.text)
+    }
+}
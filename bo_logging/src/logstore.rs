@@ -1,26 +1,75 @@
 use super::*;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
+use std::collections::VecDeque;
+
+/// How many entries the log keeps before the oldest ones are dropped
+pub const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+struct LogStore {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+}
+
+impl LogStore {
+    fn push(&mut self, entry: LogEntry) {
+        // The same message again straight after is folded into the last entry
+        if let Some(last) = self.entries.back_mut() {
+            if last.repeats(&entry) {
+                last.count += entry.count;
+                last.turn = entry.turn;
+                return;
+            }
+        }
+
+        while self.entries.len() >= self.capacity.max(1) {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
 
 lazy_static! {
-    static ref LOG: Mutex<Vec<Vec<LogFragment>>> = Mutex::new(Vec::new());
+    static ref LOG: Mutex<LogStore> =
+        Mutex::new(LogStore { entries: VecDeque::new(), capacity: DEFAULT_LOG_CAPACITY });
 }
 
-pub fn clear_log() { LOG.lock().clear(); }
-pub fn append_entry(fragments: Vec<LogFragment>) { LOG.lock().push(fragments); }
+pub fn clear_log() { LOG.lock().entries.clear(); }
+
+/// Adds an entry, stamped with the current turn, and copies it to every sink
+pub fn append_entry(category: LogCategory, source: Option<LogSource>, fragments: Vec<LogFragment>) {
+    let turn = get_event_count(TURN_DONE_EVENT);
+    let entry = LogEntry { turn, category, source, count: 1, fragments };
+    write_to_sinks(&entry);
+    LOG.lock().push(entry);
+}
+
+/// Sets how many entries are kept, dropping the oldest if there are already more
+pub fn set_log_capacity(capacity: usize) {
+    let mut log = LOG.lock();
+    log.capacity = capacity.max(1);
+    while log.entries.len() > log.capacity {
+        log.entries.pop_front();
+    }
+}
+
+pub fn log_capacity() -> usize { LOG.lock().capacity }
 
 #[allow(unused_must_use)]
 pub fn print_log(draw_batch: &mut DrawBatch, log_rect: Rect) {
     let mut block =
         TextBlock::new(log_rect.x1 + 1, log_rect.y1 + 1, log_rect.width() - 1, log_rect.height() - 2);
 
-    LOG.lock().iter().rev().take(5).for_each(|log| {
+    LOG.lock().entries.iter().rev().take(5).for_each(|log| {
         let mut buf = TextBuilder::empty();
 
         buf.fg(WHITE).append("> ");
-        log.iter().for_each(|frag| {
+        log.fragments.iter().for_each(|frag| {
             buf.fg(frag.color).bg(BLACK).line_wrap(&frag.text);
         });
+        if let Some(suffix) = log.repeat_suffix() {
+            buf.fg(GRAY).line_wrap(&suffix);
+        }
 
         buf.ln();
         block.print(&buf);
@@ -30,9 +79,42 @@ pub fn print_log(draw_batch: &mut DrawBatch, log_rect: Rect) {
     block.render_to_draw_batch(draw_batch);
 }
 
-pub fn clone_log() -> Vec<Vec<LogFragment>> { LOG.lock().clone() }
+/// Every entry in the given category, oldest first
+pub fn log_entries(category: Option<LogCategory>) -> Vec<LogEntry> {
+    let log = LOG.lock();
+    log.entries.iter().filter(|entry| category.map_or(true, |c| entry.category == c)).cloned().collect()
+}
+
+#[derive(Serialize)]
+struct JsonLogLine<'a> {
+    turn: i32,
+    category: LogCategory,
+    source: Option<&'a LogSource>,
+    count: u32,
+    text: String,
+}
+
+/// The log as one JSON object per line, for feeding to analysis tools
+pub fn log_to_json_lines(category: Option<LogCategory>) -> Result<String, serde_json::Error> {
+    let mut lines = String::new();
+    for entry in log_entries(category).iter() {
+        let line = JsonLogLine {
+            turn: entry.turn,
+            category: entry.category,
+            source: entry.source.as_ref(),
+            count: entry.count,
+            text: entry.text(),
+        };
+        lines.push_str(&serde_json::to_string(&line)?);
+        lines.push('\n');
+    }
+    Ok(lines)
+}
+
+pub fn clone_log() -> Vec<LogEntry> { LOG.lock().entries.iter().cloned().collect() }
 
-pub fn restore_log(log: &mut Vec<Vec<LogFragment>>) {
-    LOG.lock().clear();
-    LOG.lock().append(log);
+pub fn restore_log(log: &mut Vec<LogEntry>) {
+    let mut store = LOG.lock();
+    store.entries.clear();
+    log.drain(..).for_each(|entry| store.push(entry));
 }
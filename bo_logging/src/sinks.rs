@@ -0,0 +1,162 @@
+use super::*;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+
+/// Somewhere besides the on-screen log that game messages are copied to
+pub trait LogSink: Send {
+    fn write(&mut self, entry: &LogEntry);
+}
+
+lazy_static! {
+    static ref SINKS: Mutex<Vec<Box<dyn LogSink>>> = Mutex::new(Vec::new());
+}
+
+/// Every message logged from now on is also handed to this sink
+pub fn add_sink<S: LogSink + 'static>(sink: S) { SINKS.lock().push(Box::new(sink)); }
+
+pub fn clear_sinks() { SINKS.lock().clear(); }
+
+pub(crate) fn write_to_sinks(entry: &LogEntry) { SINKS.lock().iter_mut().for_each(|sink| sink.write(entry)); }
+
+/// How an entry reads outside the game, e.g. "[   12] Combat  Goblin: Goblin hits Danny for 3 hp."
+pub fn format_entry(entry: &LogEntry) -> String {
+    match &entry.source {
+        Some(source) => {
+            format!("[{:>5}] {:<8} {}: {}", entry.turn, entry.category.label(), source.name, entry.text())
+        }
+        None => format!("[{:>5}] {:<8} {}", entry.turn, entry.category.label(), entry.text()),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// log crate
+///////////////////////////////////////////////////////////////////////////////
+
+/// Passes messages on to the `log` crate under the "game" target, so env_logger or any other logger
+/// can pick them up. Chatter goes out at debug and trace, things that matter to the run at info.
+#[derive(Default)]
+pub struct LogCrateSink;
+
+impl LogSink for LogCrateSink {
+    fn write(&mut self, entry: &LogEntry) {
+        let level = match entry.category {
+            LogCategory::Status | LogCategory::System => log::Level::Info,
+            LogCategory::Combat | LogCategory::Loot => log::Level::Debug,
+            LogCategory::Dialogue => log::Level::Trace,
+        };
+        log::log!(target: "game", level, "{}", format_entry(entry));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// Browser console
+///////////////////////////////////////////////////////////////////////////////
+
+/// Writes messages to the browser console on wasm, and to stdout everywhere else
+#[derive(Default)]
+pub struct ConsoleSink;
+
+impl LogSink for ConsoleSink {
+    fn write(&mut self, entry: &LogEntry) { console::log(format_entry(entry)); }
+}
+
+/// A `log` backend for the browser, where env_logger can't write anywhere
+struct ConsoleLogger;
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool { metadata.level() <= log::max_level() }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            console::log(format!("{} [{}] {}", record.level(), record.target(), record.args()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static CONSOLE_LOGGER: ConsoleLogger = ConsoleLogger;
+
+/// Sends the `log` crate's output to the console. Used on wasm in place of env_logger.
+pub fn init_console_logger(level: log::LevelFilter) {
+    if log::set_logger(&CONSOLE_LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// Rotating file
+///////////////////////////////////////////////////////////////////////////////
+
+/// Appends messages to a text file. Once the file passes `max_bytes` it's moved aside to
+/// `<path>.1`, older copies shuffle up to `<path>.<max_files>`, and a fresh file is started.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct RotatingFileSink {
+    path: std::path::PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: Option<std::fs::File>,
+    written: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RotatingFileSink {
+    pub fn new<P: Into<std::path::PathBuf>>(path: P, max_bytes: u64, max_files: usize) -> Self {
+        Self { path: path.into(), max_bytes, max_files, file: None, written: 0 }
+    }
+
+    fn rotated_path(&self, n: usize) -> std::path::PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", n));
+        path.into()
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.file = None;
+
+        if self.max_files == 0 {
+            return std::fs::remove_file(&self.path);
+        }
+
+        for n in (1..self.max_files).rev() {
+            let from = self.rotated_path(n);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated_path(n + 1))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))
+    }
+
+    fn open(&mut self) -> std::io::Result<&mut std::fs::File> {
+        if self.file.is_none() {
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+            self.written = file.metadata()?.len();
+            self.file = Some(file);
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+
+    fn try_write(&mut self, line: &str) -> std::io::Result<()> {
+        use std::io::Write;
+
+        self.open()?;
+        if self.written > 0 && self.written + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let file = self.open()?;
+        file.write_all(line.as_bytes())?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LogSink for RotatingFileSink {
+    fn write(&mut self, entry: &LogEntry) {
+        let line = format!("{}\n", format_entry(entry));
+        if let Err(e) = self.try_write(&line) {
+            log::warn!("Could not write the game log to {}: {}", self.path.display(), e);
+        }
+    }
+}
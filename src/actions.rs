@@ -4,7 +4,7 @@ pub trait VirtualGameKey {
     fn get_key(&self) -> Option<GameKey>;
 }
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GameKey {
     // Movement
     Up,
@@ -26,35 +26,86 @@ pub enum GameKey {
     Remove,
     Apply,
     Equip,
+    Search,
+    Disarm,
+    Butcher,
+    Orders,
+    Spellbook,
+    MessageLog,
+    Look,
+    CloseDoor,
+    LockDoor,
+    AutoExplore,
 }
 
-fn key_mapping(key: VirtualKeyCode) -> Option<GameKey> {
-    match key {
-        // Movement
-        VirtualKeyCode::Up | VirtualKeyCode::Numpad8 | VirtualKeyCode::K => Some(GameKey::Up),
-        VirtualKeyCode::Down | VirtualKeyCode::Numpad2 | VirtualKeyCode::J => Some(GameKey::Down),
-        VirtualKeyCode::Left | VirtualKeyCode::Numpad4 | VirtualKeyCode::H => Some(GameKey::Left),
-        VirtualKeyCode::Right | VirtualKeyCode::Numpad6 | VirtualKeyCode::L => Some(GameKey::Right),
-        VirtualKeyCode::Y | VirtualKeyCode::Numpad7 => Some(GameKey::LeftUp),
-        VirtualKeyCode::U | VirtualKeyCode::Numpad9 => Some(GameKey::RightUp),
-        VirtualKeyCode::B | VirtualKeyCode::Numpad1 => Some(GameKey::LeftDown),
-        VirtualKeyCode::N | VirtualKeyCode::Numpad3 => Some(GameKey::RightDown),
+impl GameKey {
+    pub const ALL: [GameKey; 28] = [
+        GameKey::Up,
+        GameKey::Down,
+        GameKey::Left,
+        GameKey::Right,
+        GameKey::LeftUp,
+        GameKey::LeftDown,
+        GameKey::RightUp,
+        GameKey::RightDown,
+        GameKey::TakeStairs,
+        GameKey::Escape,
+        GameKey::Select,
+        GameKey::SkipTurn,
+        GameKey::Pickup,
+        GameKey::Inventory,
+        GameKey::Drop,
+        GameKey::Remove,
+        GameKey::Apply,
+        GameKey::Equip,
+        GameKey::Search,
+        GameKey::Disarm,
+        GameKey::Butcher,
+        GameKey::Orders,
+        GameKey::Spellbook,
+        GameKey::MessageLog,
+        GameKey::Look,
+        GameKey::CloseDoor,
+        GameKey::LockDoor,
+        GameKey::AutoExplore,
+    ];
 
-        // Actions
-        VirtualKeyCode::Period => Some(GameKey::TakeStairs),
-        VirtualKeyCode::Escape => Some(GameKey::Escape),
-        VirtualKeyCode::Return => Some(GameKey::Select),
-        VirtualKeyCode::Space => Some(GameKey::SkipTurn),
-        VirtualKeyCode::G => Some(GameKey::Pickup),
-        VirtualKeyCode::I => Some(GameKey::Inventory),
-        VirtualKeyCode::D => Some(GameKey::Drop),
-        VirtualKeyCode::R => Some(GameKey::Remove),
-        VirtualKeyCode::A => Some(GameKey::Apply),
-        VirtualKeyCode::E => Some(GameKey::Equip),
-        _ => None,
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameKey::Up => "Move up",
+            GameKey::Down => "Move down",
+            GameKey::Left => "Move left",
+            GameKey::Right => "Move right",
+            GameKey::LeftUp => "Move up-left",
+            GameKey::LeftDown => "Move down-left",
+            GameKey::RightUp => "Move up-right",
+            GameKey::RightDown => "Move down-right",
+            GameKey::TakeStairs => "Take stairs",
+            GameKey::Escape => "Back / Quit",
+            GameKey::Select => "Select",
+            GameKey::SkipTurn => "Wait a turn",
+            GameKey::Pickup => "Pick up",
+            GameKey::Inventory => "Inventory",
+            GameKey::Drop => "Drop",
+            GameKey::Remove => "Remove",
+            GameKey::Apply => "Apply",
+            GameKey::Equip => "Equip",
+            GameKey::Search => "Search",
+            GameKey::Disarm => "Disarm trap",
+            GameKey::Butcher => "Butcher",
+            GameKey::Orders => "Party orders",
+            GameKey::Spellbook => "Spellbook",
+            GameKey::MessageLog => "Message log",
+            GameKey::Look => "Look",
+            GameKey::CloseDoor => "Close door",
+            GameKey::LockDoor => "Lock door",
+            GameKey::AutoExplore => "Auto-explore",
+        }
     }
 }
 
+fn key_mapping(key: VirtualKeyCode) -> Option<GameKey> { keybindings::game_key(key) }
+
 impl VirtualGameKey for VirtualKeyCode {
     fn get_key(&self) -> Option<GameKey> { key_mapping(*self) }
 }
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionOrder {
+    Follow,
+    Stay,
+    Attack(Entity),
+}
+
+/// A member of the player's party. Companions fight monsters and follow the player between levels.
+#[derive(Component, Debug, Clone)]
+pub struct Companion {
+    pub order: CompanionOrder,
+}
+
+/// An NPC that joins the party when the player bumps into it, saying its message
+#[derive(Component, Debug, Clone)]
+pub struct Recruitable {
+    pub message: String,
+}
+
+impl_new!(Companion, order: CompanionOrder);
+impl_new!(Recruitable, message: String);
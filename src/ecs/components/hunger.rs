@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum HungerState {
     WellFed,
     Normal,
@@ -14,7 +14,36 @@ pub struct HungerClock {
     pub state: HungerState,
 }
 
+/// Food restores this many turns of being well fed. Without a value of its own, it uses the hunger config's default.
 #[derive(Component, Debug, Serialize, Deserialize, Clone, Default)]
-pub struct ProvidesFood {}
+pub struct ProvidesFood {
+    pub nutrition: Option<i32>,
+}
+
+/// Corpses turn rotten once they are this many turns old
+pub const CORPSE_ROT_TURNS: i32 = 100;
+/// ...and are gone entirely at this age
+pub const CORPSE_DECAY_TURNS: i32 = 300;
+
+/// The remains of a creature. Corpses can be eaten or butchered, and rot away as they age.
+#[derive(Component, Debug, Clone)]
+pub struct Corpse {
+    pub name: String,
+    pub age: i32,
+}
+
+impl Corpse {
+    pub fn new<S: ToString>(name: S) -> Self { Self { name: name.to_string(), age: 0 } }
+
+    pub fn is_rotten(&self) -> bool { self.age >= CORPSE_ROT_TURNS }
+}
+
+/// Food poisoning, usually from eating something that had been lying around for too long
+#[derive(Component, Debug, Clone)]
+pub struct Sick {
+    pub turns: i32,
+}
 
 impl_new!(HungerClock, state: HungerState, duration: i32);
+impl_new!(Sick, turns: i32);
+impl_new!(ProvidesFood, nutrition: Option<i32>);
@@ -3,9 +3,18 @@ use crate::prelude::*;
 #[derive(Component, Debug)]
 pub struct ProvidesHealing(pub i32);
 
+#[derive(Component, Debug)]
+pub struct ProvidesMana(pub i32);
+
 #[derive(Component, Debug)]
 pub struct InflictsDamage(pub i32);
 
+/// Permanently adds a spell to the reader's spellbook
+#[derive(Component, Debug, Clone)]
+pub struct TeachesSpell {
+    pub spell: String,
+}
+
 #[derive(Component, Debug)]
 pub struct Confusion {
     pub turns: i32,
@@ -14,4 +23,13 @@ pub struct Confusion {
 #[derive(Component, Debug, Serialize, Deserialize, Clone)]
 pub struct MagicMapper {}
 
+/// Leaves fire, gas, smoke or water on the tiles it hits
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct SpawnsTileEffect {
+    pub kind: TileEffectKind,
+    pub turns: i32,
+}
+
 impl_new!(Confusion, turns: i32);
+impl_new!(TeachesSpell, spell: String);
+impl_new!(SpawnsTileEffect, kind: TileEffectKind, turns: i32);
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct LightSource {
+    pub radius: i32,
+    pub color: RGB,
+}
+
+impl LightSource {
+    pub fn new(radius: i32, color: RGB) -> Self { Self { radius, color } }
+}
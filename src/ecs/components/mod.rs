@@ -1,31 +1,39 @@
 mod backpack;
 mod combat;
+mod companion;
 mod description;
 mod equipment;
 mod fov;
 mod glyph;
 mod hunger;
 mod items;
+mod light;
 mod name;
 mod particle;
 mod position;
 mod ranged;
+mod schedule;
+mod spells;
 mod stats;
 mod tags;
 mod trigger;
 
 pub use backpack::InBackpack;
 pub use combat::*;
+pub use companion::*;
 pub use description::Description;
 pub use equipment::*;
 pub use fov::{BlocksVisibility, FieldOfView};
 pub use glyph::{Glyph, RenderOrder};
-pub use hunger::{HungerClock, HungerState, ProvidesFood};
+pub use hunger::{Corpse, HungerClock, HungerState, ProvidesFood, Sick, CORPSE_DECAY_TURNS, CORPSE_ROT_TURNS};
 pub use items::*;
+pub use light::LightSource;
 pub use name::Naming;
 pub use particle::*;
 pub use position::OtherLevelPosition;
 pub use ranged::*;
-pub use stats::CombatStats;
+pub use schedule::*;
+pub use spells::*;
+pub use stats::{CombatStats, Experience, Gold};
 pub use tags::*;
 pub use trigger::*;
@@ -1,5 +1,10 @@
 use crate::prelude::*;
 
+/// How long a projectile takes to cross one tile
+pub const PARTICLE_LINE_STEP_MS: f32 = 30.0;
+/// How long a burst takes to spread out by one tile
+pub const PARTICLE_BURST_RING_MS: f32 = 60.0;
+
 #[derive(Clone)]
 pub struct ParticleAnimation {
     pub timer: f32,
@@ -8,6 +13,8 @@ pub struct ParticleAnimation {
     pub current_step: usize,
 }
 
+impl_new!(ParticleAnimation, timer: f32, step_time: f32, path: Vec<Point>, current_step: usize);
+
 #[derive(Component, Clone)]
 pub struct ParticleLifetime {
     pub lifetime_ms: f32,
@@ -16,11 +23,19 @@ pub struct ParticleLifetime {
 
 impl_new!(ParticleLifetime, lifetime_ms: f32, animation: Option<ParticleAnimation>);
 
+/// Marks a particle the monsters wait on before taking their turn
+#[derive(Component, Clone, Copy)]
+pub struct BlocksTurn;
+
 pub struct ParticleRequest {
     pub pt: Point,
     pub color: ColorPair,
     pub glyph: FontCharType,
     pub lifetime: f32,
+    /// Milliseconds before the particle appears
+    pub delay_ms: f32,
+    pub animation: Option<ParticleAnimation>,
+    pub blocking: bool,
 }
 
 #[derive(Component, Serialize, Deserialize, Clone)]
@@ -39,7 +54,17 @@ pub struct SpawnParticleBurst {
 
 impl_new!(SpawnParticleLine, glyph: FontCharType, color: RGB, lifetime_ms: f32);
 impl_new!(SpawnParticleBurst, glyph: FontCharType, color: RGB, lifetime_ms: f32);
-impl_new!(ParticleRequest, pt: Point, color: ColorPair, glyph: FontCharType, lifetime: f32);
+
+/// Whether turns hold off until blocking animations, like projectiles and blasts, have played out.
+/// With it off they play over the following turns instead.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationSettings {
+    pub wait_for_blocking: bool,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self { Self { wait_for_blocking: true } }
+}
 
 #[derive(Default)]
 pub struct ParticleBuilder {
@@ -50,6 +75,73 @@ impl ParticleBuilder {
     pub fn new() -> ParticleBuilder { ParticleBuilder { requests: Vec::new() } }
 
     pub fn request(&mut self, pt: Point, color: ColorPair, glyph: FontCharType, lifetime: f32) {
-        self.requests.push(ParticleRequest::new(pt, color, glyph, lifetime));
+        self.requests.push(ParticleRequest {
+            pt,
+            color,
+            glyph,
+            lifetime,
+            delay_ms: 0.0,
+            animation: None,
+            blocking: false,
+        });
     }
+
+    /// A projectile flying from `start` to `end`, leaving a fading trail behind it.
+    /// Returns how long it takes to arrive.
+    pub fn request_line(&mut self, start: Point, end: Point, line: &SpawnParticleLine) -> f32 {
+        let path = line2d(LineAlg::Bresenham, start, end).into_iter().skip(1).collect::<Vec<_>>();
+        if path.is_empty() {
+            return 0.0;
+        }
+
+        let flight_ms = path.len() as f32 * PARTICLE_LINE_STEP_MS;
+        let trail_color = ColorPair::new(line.color.lerp(RGB::named(BLACK), 0.5), BLACK);
+        for (i, pt) in path.iter().enumerate() {
+            self.requests.push(ParticleRequest {
+                pt: *pt,
+                color: trail_color,
+                glyph: line.glyph,
+                lifetime: line.lifetime_ms,
+                delay_ms: (i + 1) as f32 * PARTICLE_LINE_STEP_MS,
+                animation: None,
+                blocking: true,
+            });
+        }
+
+        self.requests.push(ParticleRequest {
+            pt: path[0],
+            color: ColorPair::new(line.color, BLACK),
+            glyph: line.glyph,
+            lifetime: flight_ms,
+            delay_ms: 0.0,
+            animation: Some(ParticleAnimation::new(0.0, PARTICLE_LINE_STEP_MS, path, 0)),
+            blocking: true,
+        });
+
+        flight_ms
+    }
+
+    /// A blast that spreads out ring by ring from `center` over the given tiles
+    pub fn request_burst(
+        &mut self,
+        center: Point,
+        tiles: &[Point],
+        burst: &SpawnParticleBurst,
+        delay_ms: f32,
+    ) {
+        for pt in tiles.iter() {
+            let ring = DistanceAlg::Pythagoras.distance2d(center, *pt).round();
+            self.requests.push(ParticleRequest {
+                pt: *pt,
+                color: ColorPair::new(burst.color, BLACK),
+                glyph: burst.glyph,
+                lifetime: burst.lifetime_ms,
+                delay_ms: delay_ms + ring * PARTICLE_BURST_RING_MS,
+                animation: None,
+                blocking: true,
+            });
+        }
+    }
+
+    pub fn has_blocking_requests(&self) -> bool { self.requests.iter().any(|request| request.blocking) }
 }
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+/// Game turns that make up one hour of the town's day
+pub const TURNS_PER_HOUR: i32 = 10;
+/// The player sets out in the morning
+const FIRST_HOUR: i32 = 8;
+
+/// The hour of the day (0-23), worked out from how many turns have passed
+pub fn hour_of_day() -> i32 {
+    (FIRST_HOUR + bo_logging::get_event_count(TURN_DONE_EVENT) / TURNS_PER_HOUR) % 24
+}
+
+/// Where a townsperson wants to be during part of the day
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulePlace {
+    /// Anywhere inside a building of the given kind
+    Building(BuildingTag),
+    /// Any tile of the given type, such as the bridges for dock workers
+    Tile(TileType),
+    /// Stroll around wherever they happen to be
+    Wander,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct ScheduleEntry {
+    pub hour: i32,
+    pub place: SchedulePlace,
+}
+
+/// A daily routine. Each entry holds from its hour until the next entry's hour.
+#[derive(Component, Debug, Clone)]
+pub struct Schedule {
+    pub entries: Vec<ScheduleEntry>,
+    pub destination: Option<usize>,
+}
+
+impl Schedule {
+    pub fn new(mut entries: Vec<ScheduleEntry>) -> Self {
+        entries.sort_by_key(|entry| entry.hour);
+        Self { entries, destination: None }
+    }
+
+    /// The place for the given hour; before the first entry the last one still holds from the night before
+    pub fn place_at(&self, hour: i32) -> SchedulePlace {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.hour <= hour)
+            .or_else(|| self.entries.last())
+            .map_or(SchedulePlace::Wander, |entry| entry.place)
+    }
+}
+
+/// Lines an NPC calls out now and then when the player is close by
+#[derive(Component, Debug, Clone)]
+pub struct Barks {
+    pub lines: Vec<String>,
+}
+
+impl_new!(Barks, lines: Vec<String>);
@@ -0,0 +1,39 @@
+use crate::prelude::*;
+
+/// The pool of magical energy spells are paid from
+#[derive(Component, Debug, Clone)]
+pub struct Mana {
+    pub current: i32,
+    pub max: i32,
+}
+
+impl Mana {
+    pub fn new(max: i32) -> Self { Self { current: max, max } }
+}
+
+/// One entity per spell in `spells.ron` is spawned at the start of a game. It carries the
+/// spell's effects, so casting goes through the same triggers as using an item.
+#[derive(Component, Debug, Clone)]
+pub struct SpellTemplate {
+    pub mana_cost: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct KnownSpell {
+    pub name: String,
+    pub mana_cost: i32,
+}
+
+/// The spells an entity has learned and can cast from its spellbook
+#[derive(Component, Debug, Clone, Default)]
+pub struct KnownSpells {
+    pub spells: Vec<KnownSpell>,
+}
+
+impl KnownSpells {
+    pub fn knows(&self, name: &str) -> bool { self.spells.iter().any(|spell| spell.name == name) }
+}
+
+impl_new!(SpellTemplate, mana_cost: i32);
+impl_new!(KnownSpell, name: String, mana_cost: i32);
+impl_new!(KnownSpells, spells: Vec<KnownSpell>);
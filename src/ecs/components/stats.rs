@@ -14,4 +14,35 @@ impl CombatStats {
 
         CombatStats { max_hp, hp, defense, power }
     }
+
+    /// A rough idea of how hurt something looks, without giving away the numbers
+    pub fn health_estimate(&self) -> (&'static str, (u8, u8, u8)) {
+        match self.hp * 100 / self.max_hp.max(1) {
+            100.. => ("Unhurt", GREEN),
+            75..=99 => ("Lightly wounded", YELLOW),
+            50..=74 => ("Wounded", ORANGE),
+            25..=49 => ("Badly wounded", RED),
+            _ => ("Almost dead", RED),
+        }
+    }
 }
+
+/// How far along the player is. Levelling up raises health, mana and power.
+#[derive(Component, Debug, Clone)]
+pub struct Experience {
+    pub level: i32,
+    pub xp: i32,
+}
+
+impl Experience {
+    pub fn new() -> Self { Self { level: 1, xp: 0 } }
+
+    /// XP needed to reach the next level, counted from the start of this one
+    pub fn xp_to_next_level(&self) -> i32 { self.level * 500 }
+}
+
+impl_default!(Experience);
+
+/// Coins carried. Monsters drop theirs into their killer's purse.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Gold(pub i32);
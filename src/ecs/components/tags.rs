@@ -22,9 +22,31 @@ pub struct Dead;
 #[derive(Component, Debug, Serialize, Deserialize, Reflect)]
 pub struct Hidden;
 
+/// Doors hold whether they are currently open
 #[derive(Component, Debug, Serialize, Deserialize)]
 pub struct Door(pub bool);
 
+/// A door that can be locked and unlocked with the named key item
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Lock {
+    pub key: String,
+    pub locked: bool,
+}
+
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct CanOpenDoors {}
+
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Flying {}
+
+/// Can cross swimmable tiles that are too deep to walk through
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct CanSwim {}
+
+/// Set when an entity wades onto a slowing tile; it loses its next action
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Slowed {}
+
 #[derive(Component, Debug, Serialize, Deserialize, Clone)]
 pub struct Bystander {}
 
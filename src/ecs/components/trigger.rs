@@ -1,7 +1,21 @@
 use crate::prelude::*;
 
+/// Restricts which movers can set off an entry trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerFilter {
+    Creatures,
+    PlayerOnly,
+    NotFlying,
+}
+
 #[derive(Component, Default, Debug, Clone, Serialize, Deserialize)]
-pub struct EntryTrigger {}
+pub struct EntryTrigger {
+    pub filters: Vec<TriggerFilter>,
+}
 
 #[derive(Component, Default, Debug, Clone, Serialize, Deserialize)]
 pub struct SingleActivation {}
+
+/// Marks an entity that stepped onto a new tile this turn
+#[derive(Component, Default, Debug, Clone, Serialize, Deserialize)]
+pub struct EntityMoved {}
@@ -1,6 +1,12 @@
 use super::*;
 
 pub fn inflict_damage(world: &mut World, effect: &EffectSpawner, target: Entity) {
+    if let EffectType::Damage(amount) = effect.effect_type {
+        if world.get::<CombatStats>(target).is_some() {
+            record_damage(world, effect, target, amount);
+        }
+    }
+
     if let Some(mut stats) = world.get_mut::<CombatStats>(target) {
         if let EffectType::Damage(amount) = effect.effect_type {
             stats.hp -= amount;
@@ -18,28 +24,109 @@ pub fn inflict_damage(world: &mut World, effect: &EffectSpawner, target: Entity)
     }
 }
 
+fn record_damage(world: &mut World, effect: &EffectSpawner, target: Entity, amount: i32) {
+    let player = *world.resource::<Entity>();
+
+    if effect.creator == Some(player) && target != player {
+        bo_logging::record_event(DAMAGE_INFLICT_EVENT, amount);
+    }
+
+    if target == player {
+        bo_logging::record_event(DAMAGE_TAKE_EVENT, amount);
+        let source = damage_source(world, effect.creator, target);
+        world.resource_mut::<RunStats>().last_damage_source = Some(source);
+    }
+}
+
+/// Describes what hurt the target, for the cause of death
+fn damage_source(world: &World, creator: Option<Entity>, target: Entity) -> String {
+    if let Some(name) = creator.and_then(|creator| world.get::<Naming>(creator)) {
+        let article = if name.0.starts_with(['A', 'E', 'I', 'O', 'U']) { "an" } else { "a" };
+        return format!("killed by {} {}", article, name.0);
+    }
+
+    let map = world.resource::<Map>();
+    let tile = world.get::<Point>(target).map(|pt| map.point2d_to_index(*pt));
+    match tile.and_then(|idx| map.tile_effect(idx)) {
+        Some(TileEffectKind::Fire) => return "burned to death".to_string(),
+        Some(TileEffectKind::PoisonGas) => return "choked on poison gas".to_string(),
+        _ => {}
+    }
+
+    if tile.map_or(false, |idx| map.tiles[idx].damage > 0) {
+        return "killed by the terrain".to_string();
+    }
+
+    if world.get::<HungerClock>(target).map_or(false, |clock| clock.state == HungerState::Starving) {
+        return "starved to death".to_string();
+    }
+
+    if world.get::<Sick>(target).is_some() {
+        return "died of food poisoning".to_string();
+    }
+
+    "killed by unknown causes".to_string()
+}
+
 pub fn bloodstain(world: &mut World, tile_idx: usize, blood_color: &RGB) {
     let mut map = world.resource_mut::<Map>();
     map.bloodstains.insert(tile_idx, *blood_color);
 }
 
-pub fn death(world: &mut World, _effect: &EffectSpawner, target: Entity) {
+pub fn death(world: &mut World, effect: &EffectSpawner, target: Entity) {
+    // Several killing blows can land in the same turn, only the first one counts
+    if world.get::<Dead>(target).is_some() {
+        return;
+    }
+
     if let Some(pos) = entity_position(world, target) {
         crate::spatial::remove_entity(target, pos as usize);
     }
 
-    if world.get::<Player>(target).is_none() {
+    let player = *world.resource::<Entity>();
+    if target == player {
+        let mut run_stats = world.resource_mut::<RunStats>();
+        run_stats.cause_of_death = run_stats.last_damage_source.clone();
+    } else {
+        if effect.creator == Some(player) {
+            if let Some(name) = world.get::<Naming>(target).map(|n| n.0.clone()) {
+                world.resource_mut::<RunStats>().record_kill(&name);
+            }
+        }
+
+        if let Some(killer) = effect.creator {
+            experience::reward_killer(world, killer, target);
+        }
+
         let mut entity = world.entity_mut(target);
 
-        entity.remove_bundle::<MonsterBundle>();
+        // Strip whatever kept it going. Not every creature has the whole bundle, so take what it does have.
+        entity.remove_bundle_intersection::<MonsterBundle>();
+        entity.remove::<Bystander>();
+        entity.remove::<BlocksTile>();
+        entity.remove::<FieldOfView>();
+        entity.remove::<LightSource>();
+        entity.remove::<HungerClock>();
+        entity.remove::<Companion>();
+        entity.remove::<Recruitable>();
+        entity.remove::<Schedule>();
+        entity.remove::<Barks>();
 
         if let Some(mut glyph) = entity.get_mut::<Glyph>() {
             glyph.render_order = RenderOrder::Corpse;
             glyph.color = ColorPair::new(DARK_GRAY, BLACK);
         }
 
-        if let Some(name) = entity.get::<Naming>() {
-            entity.insert(Naming(format!("Dead {}", name.0))).insert(Dead);
+        // What's left behind is an item that can be picked up, eaten or butchered
+        if let Some(name) = entity.get::<Naming>().map(|n| n.0.clone()) {
+            let nutrition = raws::get_hunger_config(&RAWS.lock()).corpse_nutrition;
+            entity
+                .insert(Naming(format!("{} corpse", name)))
+                .insert(Corpse::new(name))
+                .insert(Item {})
+                .insert(Consumable {})
+                .insert(ProvidesFood::new(Some(nutrition)))
+                .insert(Dead);
         }
     }
 }
@@ -50,6 +137,7 @@ pub fn heal_damage(world: &mut World, effect: &EffectSpawner, target: Entity) {
             stats.hp = i32::min(stats.max_hp, stats.hp + amount);
 
             bo_logging::Logger::new()
+                .category(LogCategory::Status)
                 .append("You heal")
                 .append_with_color(format!("{}", amount), GREEN)
                 .append("hp")
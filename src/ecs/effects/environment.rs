@@ -0,0 +1,9 @@
+use super::*;
+
+pub fn tile_effect(world: &mut World, tile_idx: usize, kind: TileEffectKind, turns: i32) {
+    let mut map = world.resource_mut::<Map>();
+    if map.add_tile_effect(tile_idx, kind, turns) && kind == TileEffectKind::Fire {
+        // Flames burn away whatever stained the ground
+        map.bloodstains.remove(&tile_idx);
+    }
+}
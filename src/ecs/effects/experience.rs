@@ -0,0 +1,71 @@
+use super::*;
+
+/// Experience earned per point of the victim's maximum health
+const XP_PER_HP: i32 = 10;
+const LEVEL_UP_HP: i32 = 10;
+const LEVEL_UP_MANA: i32 = 5;
+
+/// Hands the victim's experience and gold to whoever killed it
+pub fn reward_killer(world: &mut World, killer: Entity, victim: Entity) {
+    let xp = world.get::<CombatStats>(victim).map_or(0, |stats| stats.max_hp * XP_PER_HP);
+    let gold = world.get::<Gold>(victim).map_or(0, |gold| gold.0);
+    let is_player = world.get::<Player>(killer).is_some();
+
+    if gold > 0 {
+        let collected = match world.get_mut::<Gold>(killer) {
+            Some(mut purse) => {
+                purse.0 += gold;
+                true
+            }
+            None => false,
+        };
+
+        if collected {
+            world.entity_mut(victim).remove::<Gold>();
+            if is_player {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Loot)
+                    .append("You collect")
+                    .append_with_color(gold, GOLD)
+                    .append("gold.")
+                    .log();
+            }
+        }
+    }
+
+    let mut levels_gained = 0;
+    if let Some(mut experience) = world.get_mut::<Experience>(killer) {
+        experience.xp += xp;
+        while experience.xp >= experience.xp_to_next_level() {
+            experience.xp -= experience.xp_to_next_level();
+            experience.level += 1;
+            levels_gained += 1;
+        }
+    }
+
+    for _ in 0..levels_gained {
+        level_up(world, killer, is_player);
+    }
+}
+
+fn level_up(world: &mut World, entity: Entity, is_player: bool) {
+    if let Some(mut stats) = world.get_mut::<CombatStats>(entity) {
+        stats.max_hp += LEVEL_UP_HP;
+        stats.hp = stats.max_hp;
+        stats.power += 1;
+    }
+
+    if let Some(mut mana) = world.get_mut::<Mana>(entity) {
+        mana.max += LEVEL_UP_MANA;
+        mana.current = mana.max;
+    }
+
+    if is_player {
+        let level = world.get::<Experience>(entity).map_or(1, |experience| experience.level);
+        bo_logging::Logger::new()
+            .category(LogCategory::Status)
+            .color(MAGENTA)
+            .append(format!("Congratulations, you are now level {}!", level))
+            .log();
+    }
+}
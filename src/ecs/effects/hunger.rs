@@ -1,8 +1,25 @@
 use super::*;
 
-pub fn well_fed(world: &mut World, _damage: &EffectSpawner, target: Entity) {
-    if let Some(mut hc) = world.get_mut::<HungerClock>(target) {
-        hc.state = HungerState::WellFed;
-        hc.duration = 20;
+pub fn well_fed(world: &mut World, effect: &EffectSpawner, target: Entity) {
+    if let EffectType::WellFed(nutrition) = effect.effect_type {
+        if let Some(mut hc) = world.get_mut::<HungerClock>(target) {
+            // Eating while already full doesn't stack, it only tops the clock up
+            hc.duration = if hc.state == HungerState::WellFed { hc.duration.max(nutrition) } else { nutrition };
+            hc.state = HungerState::WellFed;
+        }
+    }
+}
+
+pub fn make_sick(world: &mut World, effect: &EffectSpawner, target: Entity) {
+    if let EffectType::Sickness(turns) = effect.effect_type {
+        world.entity_mut(target).insert(Sick::new(turns));
+
+        if world.get::<Player>(target).is_some() {
+            bo_logging::Logger::new()
+                .category(LogCategory::Status)
+                .color(ORANGE)
+                .append("Your stomach turns. You feel sick.")
+                .log();
+        }
     }
 }
@@ -0,0 +1,53 @@
+use super::*;
+
+pub fn restore_mana(world: &mut World, effect: &EffectSpawner, target: Entity) {
+    if let EffectType::Mana(amount) = effect.effect_type {
+        if let Some(mut mana) = world.get_mut::<Mana>(target) {
+            mana.current = i32::min(mana.max, mana.current + amount);
+            add_mana_particle(target);
+        }
+    }
+}
+
+/// Adds a spell to the learner's spellbook, if it exists and isn't known already.
+/// Returns whether a new spell was learned.
+pub fn learn_spell(world: &mut World, learner: Entity, spell_name: &str) -> bool {
+    let spell = match raws::get_spell(&RAWS.lock(), spell_name) {
+        Some(spell) => spell,
+        None => {
+            log::warn!("Trying to teach an unknown spell [{}]", spell_name);
+            return false;
+        }
+    };
+
+    let is_player = world.get::<Player>(learner).is_some();
+    let mut entity = world.entity_mut(learner);
+    if !entity.contains::<KnownSpells>() {
+        entity.insert(KnownSpells::default());
+    }
+
+    let mut known = entity.get_mut::<KnownSpells>().unwrap();
+    if known.knows(&spell.name) {
+        if is_player {
+            bo_logging::Logger::new()
+                .category(LogCategory::Status)
+                .append("You already know")
+                .color(CYAN)
+                .append(&spell.name)
+                .log();
+        }
+        return false;
+    }
+
+    known.spells.push(KnownSpell::new(spell.name.clone(), spell.mana_cost));
+    if is_player {
+        bo_logging::Logger::new()
+            .category(LogCategory::Status)
+            .append("You learn the spell")
+            .color(CYAN)
+            .append(&spell.name)
+            .log();
+    }
+
+    true
+}
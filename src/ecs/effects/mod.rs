@@ -4,13 +4,19 @@ use parking_lot::Mutex;
 use std::collections::VecDeque;
 
 mod damage;
+mod environment;
+mod experience;
 mod hunger;
+mod magic;
 mod particle;
 mod queries;
 mod triggers;
 
 pub use damage::*;
+pub use environment::*;
+pub use experience::*;
 pub use hunger::*;
+pub use magic::*;
 pub use particle::*;
 pub use queries::*;
 pub use triggers::*;
@@ -21,15 +27,19 @@ lazy_static! {
 
 #[derive(Debug)]
 pub enum EffectType {
-    WellFed,
+    WellFed(i32),
     EntityDeath,
     Bloodstain(RGB),
     Damage(i32),
     Healing(i32),
+    Mana(i32),
     Confusion(i32),
+    Sickness(i32),
     ItemUse(Entity),
+    SpellUse(Entity),
     TriggerFire(Entity),
     Particle { glyph: FontCharType, color: ColorPair, lifespan: f32 },
+    TileEffect { kind: TileEffectKind, turns: i32 },
 }
 
 impl EffectType {
@@ -87,6 +97,8 @@ pub fn run_effects_queue(world: &mut World) {
 fn target_applicator(world: &mut World, effect: &EffectSpawner) {
     if let EffectType::ItemUse(item) = effect.effect_type {
         triggers::item_trigger(world, effect.creator, item, &effect.targets);
+    } else if let EffectType::SpellUse(spell) = effect.effect_type {
+        triggers::spell_trigger(world, effect.creator, spell, &effect.targets);
     } else if let EffectType::TriggerFire(trigger) = effect.effect_type {
         triggers::trigger(world, effect.creator, trigger, &effect.targets);
     } else {
@@ -106,8 +118,10 @@ fn tile_effect_hits_entities(effect: &EffectType) -> bool {
         effect,
         EffectType::Damage { .. }
             | EffectType::Healing { .. }
+            | EffectType::Mana { .. }
             | EffectType::Confusion { .. }
-            | EffectType::WellFed
+            | EffectType::Sickness { .. }
+            | EffectType::WellFed { .. }
     )
 }
 
@@ -119,17 +133,20 @@ fn affect_tile(world: &mut World, effect: &EffectSpawner, tile_idx: usize) {
     match &effect.effect_type {
         EffectType::Bloodstain(color) => damage::bloodstain(world, tile_idx, color),
         EffectType::Particle { .. } => particle::particle_to_tile(world, tile_idx, effect),
+        EffectType::TileEffect { kind, turns } => environment::tile_effect(world, tile_idx, *kind, *turns),
         _ => {}
     }
 }
 
 fn affect_entity(world: &mut World, effect: &EffectSpawner, target: Entity) {
     match &effect.effect_type {
-        EffectType::WellFed => hunger::well_fed(world, effect, target),
+        EffectType::WellFed { .. } => hunger::well_fed(world, effect, target),
         EffectType::EntityDeath => damage::death(world, effect, target),
         EffectType::Healing { .. } => damage::heal_damage(world, effect, target),
+        EffectType::Mana { .. } => magic::restore_mana(world, effect, target),
         EffectType::Damage { .. } => damage::inflict_damage(world, effect, target),
         EffectType::Confusion { .. } => damage::add_confusion(world, effect, target),
+        EffectType::Sickness { .. } => hunger::make_sick(world, effect, target),
         EffectType::Particle { .. } => {
             if let Some(pos) = entity_position(world, target) {
                 particle::particle_to_tile(world, pos, effect)
@@ -140,6 +157,11 @@ fn affect_entity(world: &mut World, effect: &EffectSpawner, target: Entity) {
                 damage::bloodstain(world, pos, color)
             }
         }
+        EffectType::TileEffect { kind, turns } => {
+            if let Some(pos) = entity_position(world, target) {
+                environment::tile_effect(world, pos, *kind, *turns)
+            }
+        }
         _ => {}
     }
 }
@@ -34,3 +34,11 @@ pub fn add_heal_particle(target: Entity) {
         Targets::Single(target),
     );
 }
+
+pub fn add_mana_particle(target: Entity) {
+    add_effect(
+        None,
+        EffectType::Particle { glyph: to_cp437('‼'), color: ColorPair::new(BLUE, BLACK), lifespan: 200.0 },
+        Targets::Single(target),
+    );
+}
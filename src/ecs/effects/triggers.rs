@@ -1,15 +1,28 @@
 use super::*;
 
+const SICKNESS_TURNS: i32 = 20;
+
 pub fn item_trigger(world: &mut World, creator: Option<Entity>, item: Entity, targets: &Targets) {
     // Use the item via the generic system
     let did_something = event_trigger(world, creator, item, targets);
 
+    if did_something && creator == Some(*world.resource::<Entity>()) {
+        if let Some(name) = world.get::<Naming>(item).map(|n| n.0.clone()) {
+            world.resource_mut::<RunStats>().record_item_use(&name);
+        }
+    }
+
     // If it was a consumable, then it gets deleted
     if did_something && world.get::<Consumable>(item).is_some() {
         world.despawn(item);
     }
 }
 
+/// Spells aren't used up, so this only fires the effects on the spell's template entity
+pub fn spell_trigger(world: &mut World, creator: Option<Entity>, spell: Entity, targets: &Targets) {
+    event_trigger(world, creator, spell, targets);
+}
+
 pub fn trigger(world: &mut World, creator: Option<Entity>, trigger: Entity, targets: &Targets) {
     // The triggering item is no longer hidden
     world.entity_mut(trigger).remove::<Hidden>();
@@ -27,11 +40,20 @@ fn event_trigger(world: &mut World, creator: Option<Entity>, entity: Entity, tar
     let mut did_something = false;
 
     // Providing food
-    if world.get::<ProvidesFood>(entity).is_some() {
+    if let Some(food) = world.get::<ProvidesFood>(entity) {
         did_something = true;
+        let nutrition = food.nutrition.unwrap_or_else(|| raws::get_hunger_config(&RAWS.lock()).default_nutrition);
         let item_name = world.get::<Naming>(entity).unwrap().0.clone();
-        add_effect(creator, EffectType::WellFed, targets.clone());
-        bo_logging::Logger::new().append("You eat the").item_name(item_name).log();
+        add_effect(creator, EffectType::WellFed(nutrition), targets.clone());
+        log_eating(world, creator, &item_name);
+
+        // Raw meat is a gamble, and rotten meat even more so
+        if let Some(corpse) = world.get::<Corpse>(entity) {
+            let chance = if corpse.is_rotten() { 2 } else { 6 };
+            if crate::rng::roll_dice(1, chance) == 1 {
+                add_effect(creator, EffectType::Sickness(SICKNESS_TURNS), targets.clone());
+            }
+        }
     }
 
     // Healing
@@ -40,6 +62,20 @@ fn event_trigger(world: &mut World, creator: Option<Entity>, entity: Entity, tar
         add_effect(creator, EffectType::Healing(heal.0), targets.clone());
     }
 
+    // Mana
+    if let Some(mana) = world.get::<ProvidesMana>(entity) {
+        did_something = true;
+        add_effect(creator, EffectType::Mana(mana.0), targets.clone());
+    }
+
+    // Learning a spell, the scroll is only used up if something new was learned
+    if let Some(teaches) = world.get::<TeachesSpell>(entity) {
+        let spell = teaches.spell.clone();
+        if let Some(learner) = creator {
+            did_something |= magic::learn_spell(world, learner, &spell);
+        }
+    }
+
     // Damage
     if let Some(damage) = world.get::<InflictsDamage>(entity) {
         add_effect(creator, EffectType::Damage(damage.0), targets.clone());
@@ -52,6 +88,12 @@ fn event_trigger(world: &mut World, creator: Option<Entity>, entity: Entity, tar
         did_something = true;
     }
 
+    // Fire, gas, smoke and water
+    if let Some(spawns) = world.get::<SpawnsTileEffect>(entity) {
+        add_effect(creator, EffectType::TileEffect { kind: spawns.kind, turns: spawns.turns }, targets.clone());
+        did_something = true;
+    }
+
     // Magic mapper
     if world.get::<MagicMapper>(entity).is_some() {
         bo_logging::Logger::new().append("The map is revealed to you!").log();
@@ -62,3 +104,29 @@ fn event_trigger(world: &mut World, creator: Option<Entity>, entity: Entity, tar
 
     did_something
 }
+
+fn log_eating(world: &World, eater: Option<Entity>, item_name: &str) {
+    let eater = match eater {
+        Some(eater) => eater,
+        None => return,
+    };
+
+    if world.get::<Player>(eater).is_some() {
+        bo_logging::Logger::new()
+            .category(LogCategory::Status)
+            .append("You eat the")
+            .item_name(item_name)
+            .log();
+    } else if let (Some(name), Some(pos)) = (world.get::<Naming>(eater), world.get::<Point>(eater)) {
+        // Only mention creatures eating where the player can see it
+        if world.resource::<Map>().visible.get_bit(*pos) {
+            bo_logging::Logger::new()
+                .category(LogCategory::Status)
+                .source(eater.to_bits(), &name.0)
+                .npc_name(&name.0)
+                .append("eats the")
+                .item_name(item_name)
+                .log();
+        }
+    }
+}
@@ -13,9 +13,30 @@ pub struct WantsToMove(pub Entity, pub Point);
 #[derive(Debug)]
 pub struct WantsToAttack(pub Entity, pub Entity);
 
+#[derive(Debug)]
+pub struct WantsToRecruit(pub Entity, pub Entity);
+
+#[derive(Debug)]
+pub struct WantsToSwapPlaces(pub Entity, pub Entity);
+
 #[derive(Debug)]
 pub struct WantsToPickupItem(pub Entity, pub Entity);
 
+#[derive(Debug)]
+pub struct WantsToSearch(pub Entity);
+
+#[derive(Debug)]
+pub struct WantsToDisarm(pub Entity, pub Entity);
+
+#[derive(Debug)]
+pub struct WantsToButcher(pub Entity, pub Entity);
+
+#[derive(Debug)]
+pub struct WantsToCloseDoor(pub Entity, pub Entity);
+
+#[derive(Debug)]
+pub struct WantsToLockDoor(pub Entity, pub Entity);
+
 #[derive(Debug, Clone)]
 pub struct WantsToDropItem(pub Entity, pub Entity);
 
@@ -27,3 +48,6 @@ pub struct WantsToEquipItem(pub Entity, pub Entity);
 
 #[derive(Debug, Component)]
 pub struct WantsToUseItem(pub Entity, pub Entity, pub Option<Point>);
+
+#[derive(Debug, Clone)]
+pub struct WantsToCastSpell(pub Entity, pub Entity, pub Option<Point>);
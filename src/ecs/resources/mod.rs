@@ -1,9 +1,11 @@
 mod bundle;
 mod camera;
 mod run_criteria;
+mod run_stats;
 mod state;
 
 pub use bundle::*;
 pub use camera::*;
 pub use run_criteria::*;
+pub use run_stats::*;
 pub use state::*;
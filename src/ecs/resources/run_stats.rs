@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+/// The seed the dungeon was generated from, kept so a run can be played again
+#[derive(Debug, Clone, Copy)]
+pub struct RunSeed(pub u64);
+
+/// Everything worth remembering about the current run, shown on the game over screen and in the morgue file.
+/// Plain damage totals live in `bo_logging`'s event counters alongside the turn count.
+#[derive(Debug, Clone, Default)]
+pub struct RunStats {
+    /// Kills by the player, keyed by monster name
+    pub kills: BTreeMap<String, i32>,
+    /// Items the player used up or activated, keyed by item name
+    pub items_used: BTreeMap<String, i32>,
+    /// Turns the player spent on each depth
+    pub turns_per_depth: BTreeMap<i32, i32>,
+    pub deepest_depth: i32,
+    /// What last hurt the player, which is what gets the blame if they die
+    pub last_damage_source: Option<String>,
+    pub cause_of_death: Option<String>,
+}
+
+impl RunStats {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn record_kill(&mut self, name: &str) { *self.kills.entry(name.to_string()).or_insert(0) += 1; }
+
+    pub fn record_item_use(&mut self, name: &str) {
+        *self.items_used.entry(name.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn record_turn(&mut self, depth: i32) { *self.turns_per_depth.entry(depth).or_insert(0) += 1; }
+
+    pub fn record_depth(&mut self, depth: i32) {
+        self.deepest_depth = i32::max(self.deepest_depth, depth);
+        self.turns_per_depth.entry(depth).or_insert(0);
+    }
+
+    pub fn total_kills(&self) -> i32 { self.kills.values().sum() }
+
+    pub fn cause_of_death(&self) -> &str { self.cause_of_death.as_deref().unwrap_or("unknown") }
+}
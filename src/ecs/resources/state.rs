@@ -43,5 +43,6 @@ pub enum AIStage {
 pub enum StateLabel {
     Fov,
     Indexing,
+    Lighting,
     AIActions,
 }
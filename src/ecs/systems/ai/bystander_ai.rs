@@ -1,23 +1,116 @@
 use crate::prelude::*;
 
+/// Townsfolk only call out to the player when this close
+const BARK_DISTANCE: f32 = 6.0;
+/// One in this many turns a nearby townsperson says something
+const BARK_CHANCE: i32 = 15;
+/// One in this many turns a townsperson at their spot picks another one nearby
+const MILL_ABOUT_CHANCE: i32 = 8;
+
 pub fn bystander_ai(
+    map: Res<Map>,
     state: Res<TurnState>,
+    player_pos: Res<Point>,
+    mut commands: Commands,
     mut move_events: EventWriter<WantsToMove>,
-    bystander_q: Query<(Entity, &Point), With<Bystander>>,
+    mut bystander_q: Query<
+        (Entity, &Point, &Naming, Option<&mut Schedule>, Option<&Barks>, Option<&Slowed>),
+        With<Bystander>,
+    >,
+    door_openers_q: Query<(), With<CanOpenDoors>>,
+    swimmers_q: Query<(), Or<(With<CanSwim>, With<Flying>)>>,
 ) {
     if *state != TurnState::AITurn {
         return;
     }
 
-    for (entity, pos) in bystander_q.iter() {
-        // Try to move randomly
-        let destination = match crate::rng::range(0, 4) {
-            0 => Point::new(-1, 0),
-            1 => Point::new(1, 0),
-            2 => Point::new(0, -1),
-            _ => Point::new(0, 1),
-        } + *pos;
+    let hour = hour_of_day();
+    for (entity, pos, name, schedule, barks, slowed) in bystander_q.iter_mut() {
+        if let Some(barks) = barks {
+            bark(&map, *player_pos, entity, *pos, &name.0, barks);
+        }
+
+        if slowed.is_some() {
+            commands.entity(entity).remove::<Slowed>();
+            continue;
+        }
+
+        let step = match schedule {
+            Some(mut schedule) => {
+                let abilities =
+                    MoveAbilities::new(door_openers_q.contains(entity), swimmers_q.contains(entity));
+                follow_schedule(&PathingMap::new(&map, abilities), *pos, hour, &mut schedule)
+            }
+            None => Some(random_step(*pos)),
+        };
+
+        if let Some(step) = step {
+            move_events.send(WantsToMove(entity, step));
+        }
+    }
+}
+
+/// Works out the next step towards wherever the schedule says to be right now.
+/// Returns None when the townsperson is happy to stay put this turn.
+fn follow_schedule(pathing: &PathingMap, pos: Point, hour: i32, schedule: &mut Schedule) -> Option<Point> {
+    let map = pathing.map;
+    let tiles: &[usize] = match schedule.place_at(hour) {
+        SchedulePlace::Building(tag) => map.points_of_interest(tag),
+        SchedulePlace::Tile(tile_type) => map.tile_points_of_interest(tile_type),
+        SchedulePlace::Wander => &[],
+    };
+
+    if tiles.is_empty() {
+        schedule.destination = None;
+        return Some(random_step(pos));
+    }
+
+    let pos_idx = map.point2d_to_index(pos);
+    match schedule.destination {
+        Some(destination) if destination == pos_idx => {
+            if crate::rng::roll_dice(1, MILL_ABOUT_CHANCE) == 1 {
+                schedule.destination = None;
+            }
+            return None;
+        }
+        Some(destination) if tiles.contains(&destination) => {}
+        _ => schedule.destination = Some(tiles[crate::rng::range(0, tiles.len() as i32) as usize]),
+    }
 
-        move_events.send(WantsToMove(entity, destination));
+    let path = a_star_search(pos_idx, schedule.destination.unwrap_or(pos_idx), pathing);
+    if path.success && path.steps.len() > 1 {
+        Some(map.index_to_point2d(path.steps[1]))
+    } else {
+        // Somewhere we can't reach, try another spot next turn
+        schedule.destination = None;
+        None
     }
 }
+
+fn random_step(pos: Point) -> Point {
+    match crate::rng::range(0, 4) {
+        0 => Point::new(-1, 0),
+        1 => Point::new(1, 0),
+        2 => Point::new(0, -1),
+        _ => Point::new(0, 1),
+    } + pos
+}
+
+fn bark(map: &Map, player_pos: Point, entity: Entity, pos: Point, name: &str, barks: &Barks) {
+    if barks.lines.is_empty()
+        || !map.visible.get_bit(pos)
+        || DistanceAlg::Pythagoras.distance2d(player_pos, pos) > BARK_DISTANCE
+        || crate::rng::roll_dice(1, BARK_CHANCE) != 1
+    {
+        return;
+    }
+
+    let line = &barks.lines[crate::rng::range(0, barks.lines.len() as i32) as usize];
+    bo_logging::Logger::new()
+        .category(LogCategory::Dialogue)
+        .source(entity.to_bits(), name)
+        .npc_name(name)
+        .append("says")
+        .append(format!("\"{}\"", line))
+        .log();
+}
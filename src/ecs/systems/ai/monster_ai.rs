@@ -8,9 +8,13 @@ pub fn monster_ai(
     mut move_events: EventWriter<WantsToMove>,
     player_pos_q: Query<(Entity, &Point), (With<Player>, Without<Monster>)>,
     mut monster_q: Query<
-        (Entity, &Point, &FieldOfView, Option<&mut Confusion>),
+        (Entity, &Point, &FieldOfView, Option<&mut Confusion>, Option<&Slowed>, Option<&HungerClock>),
         (With<Monster>, Without<Player>),
     >,
+    food_q: Query<(Entity, &Point), (With<ProvidesFood>, Without<Monster>, Without<Player>)>,
+    companion_q: Query<(Entity, &Point), (With<Companion>, Without<Monster>)>,
+    door_openers_q: Query<(), With<CanOpenDoors>>,
+    swimmers_q: Query<(), Or<(With<CanSwim>, With<Flying>)>>,
 ) {
     if *state != TurnState::AITurn {
         return;
@@ -18,9 +22,14 @@ pub fn monster_ai(
 
     let (player_ent, player_pos) = player_pos_q.single();
 
-    for (entity, pos, fov, confused) in monster_q.iter_mut() {
+    for (entity, pos, fov, confused, slowed, hunger) in monster_q.iter_mut() {
         let mut can_act = true;
 
+        if slowed.is_some() {
+            can_act = false;
+            commands.entity(entity).remove::<Slowed>();
+        }
+
         if let Some(mut i_am_confused) = confused {
             can_act = false;
             i_am_confused.turns -= 1;
@@ -31,18 +40,58 @@ pub fn monster_ai(
         }
 
         if can_act {
+            let abilities = MoveAbilities::new(door_openers_q.contains(entity), swimmers_q.contains(entity));
+            let pathing = PathingMap::new(&map, abilities);
+
             let distance = DistanceAlg::Pythagoras.distance2d(*pos, *player_pos);
+            let adjacent_companion = companion_q
+                .iter()
+                .find(|(_, companion_pos)| DistanceAlg::Pythagoras.distance2d(*pos, **companion_pos) < 1.5);
+
             if distance < 1.5 {
                 attack_events.send(WantsToAttack(entity, player_ent));
+            } else if let Some((companion, _)) = adjacent_companion {
+                attack_events.send(WantsToAttack(entity, companion));
             } else if fov.visible_tiles.contains(player_pos) {
                 // Path to the player
                 let path =
-                    a_star_search(map.point2d_to_index(*pos), map.point2d_to_index(*player_pos), &*map);
+                    a_star_search(map.point2d_to_index(*pos), map.point2d_to_index(*player_pos), &pathing);
 
                 if path.success && path.steps.len() > 1 && path.steps.len() < 15 {
                     let destination = map.index_to_point2d(path.steps[1]);
                     move_events.send(WantsToMove(entity, destination));
                 }
+            } else if hunger
+                .map_or(false, |h| matches!(h.state, HungerState::Hungry | HungerState::Starving))
+            {
+                forage(&pathing, entity, *pos, fov, &food_q, &mut move_events);
+            }
+        }
+    }
+}
+
+/// Hungry monsters with nothing better to do go looking for something to eat
+fn forage(
+    pathing: &PathingMap,
+    entity: Entity,
+    pos: Point,
+    fov: &FieldOfView,
+    food_q: &Query<(Entity, &Point), (With<ProvidesFood>, Without<Monster>, Without<Player>)>,
+    move_events: &mut EventWriter<WantsToMove>,
+) {
+    let nearest = food_q
+        .iter()
+        .filter(|(_, food_pos)| fov.visible_tiles.contains(food_pos))
+        .min_by_key(|(_, food_pos)| DistanceAlg::Pythagoras.distance2d(pos, **food_pos) as i32);
+
+    if let Some((food, food_pos)) = nearest {
+        if *food_pos == pos {
+            add_effect(Some(entity), EffectType::ItemUse(food), Targets::Single(entity));
+        } else {
+            let map = pathing.map;
+            let path = a_star_search(map.point2d_to_index(pos), map.point2d_to_index(*food_pos), pathing);
+            if path.success && path.steps.len() > 1 {
+                move_events.send(WantsToMove(entity, map.index_to_point2d(path.steps[1])));
             }
         }
     }
@@ -0,0 +1,159 @@
+use crate::prelude::*;
+
+/// Companions stay within this distance of the player when following
+const FOLLOW_DISTANCE: f32 = 2.5;
+/// Companions only chase enemies this close to the player, so they don't wander off
+const LEASH_DISTANCE: f32 = 8.0;
+
+pub fn recruit(
+    mut commands: Commands,
+    mut recruit_events: EventReader<WantsToRecruit>,
+    recruit_q: Query<(&Naming, &Recruitable)>,
+) {
+    for WantsToRecruit(_, target) in recruit_events.iter() {
+        if let Ok((name, recruitable)) = recruit_q.get(*target) {
+            bo_logging::Logger::new()
+                .category(LogCategory::Dialogue)
+                .source(target.to_bits(), &name.0)
+                .npc_name(&name.0)
+                .append("says")
+                .append(&recruitable.message)
+                .log();
+            bo_logging::Logger::new()
+                .category(LogCategory::Status)
+                .source(target.to_bits(), &name.0)
+                .npc_name(&name.0)
+                .append("joins your party.")
+                .log();
+
+            commands
+                .entity(*target)
+                .remove::<Recruitable>()
+                .remove::<Bystander>()
+                .remove::<Monster>()
+                .insert(BlocksTile)
+                .insert(Companion::new(CompanionOrder::Follow));
+        }
+    }
+}
+
+pub fn swap_places(
+    map: Res<Map>,
+    player: Res<Entity>,
+    mut commands: Commands,
+    mut swap_events: EventReader<WantsToSwapPlaces>,
+    positions: Query<&Point>,
+    mut fov_q: Query<&mut FieldOfView>,
+    mut camera: ResMut<CameraView>,
+) {
+    for WantsToSwapPlaces(entity, other) in swap_events.iter() {
+        if let (Ok(from), Ok(to)) = (positions.get(*entity), positions.get(*other)) {
+            let (from, to) = (*from, *to);
+            let (from_idx, to_idx) = (map.point2d_to_index(from), map.point2d_to_index(to));
+
+            commands.entity(*entity).insert(to).insert(EntityMoved {});
+            commands.entity(*other).insert(from).insert(EntityMoved {});
+            crate::spatial::move_entity(*entity, from_idx, to_idx);
+            crate::spatial::move_entity(*other, to_idx, from_idx);
+
+            for e in [*entity, *other] {
+                if let Ok(mut fov) = fov_q.get_mut(e) {
+                    fov.is_dirty = true;
+                }
+            }
+
+            if *entity == *player {
+                commands.insert_resource(to);
+                camera.on_player_move(to);
+            }
+        }
+    }
+}
+
+pub fn companion_ai(
+    map: Res<Map>,
+    player_pos: Res<Point>,
+    mut commands: Commands,
+    mut attack_events: EventWriter<WantsToAttack>,
+    mut move_events: EventWriter<WantsToMove>,
+    mut companion_q: Query<
+        (Entity, &Point, &FieldOfView, &mut Companion, Option<&mut Confusion>, Option<&Slowed>),
+        Without<Monster>,
+    >,
+    enemy_q: Query<(Entity, &Point), (With<Monster>, Without<Companion>)>,
+    door_openers_q: Query<(), With<CanOpenDoors>>,
+    swimmers_q: Query<(), Or<(With<CanSwim>, With<Flying>)>>,
+) {
+    for (entity, pos, fov, mut companion, confused, slowed) in companion_q.iter_mut() {
+        if slowed.is_some() {
+            commands.entity(entity).remove::<Slowed>();
+            continue;
+        }
+
+        if let Some(mut confusion) = confused {
+            confusion.turns -= 1;
+            if confusion.turns < 1 {
+                commands.entity(entity).remove::<Confusion>();
+            }
+            continue;
+        }
+
+        let abilities = MoveAbilities::new(door_openers_q.contains(entity), swimmers_q.contains(entity));
+        let pathing = PathingMap::new(&map, abilities);
+
+        // Carry out an attack order for as long as the target is still around
+        if let CompanionOrder::Attack(target) = companion.order {
+            match enemy_q.get(target) {
+                Ok((_, target_pos)) => {
+                    if DistanceAlg::Pythagoras.distance2d(*pos, *target_pos) < 1.5 {
+                        attack_events.send(WantsToAttack(entity, target));
+                    } else {
+                        step_towards(&pathing, entity, *pos, *target_pos, &mut move_events);
+                    }
+                    continue;
+                }
+                Err(_) => companion.order = CompanionOrder::Follow,
+            }
+        }
+
+        // Anything within reach gets hit, whatever the orders
+        if let Some((enemy, _)) =
+            enemy_q.iter().find(|(_, enemy_pos)| DistanceAlg::Pythagoras.distance2d(*pos, **enemy_pos) < 1.5)
+        {
+            attack_events.send(WantsToAttack(entity, enemy));
+            continue;
+        }
+
+        if companion.order == CompanionOrder::Stay {
+            continue;
+        }
+
+        let nearest_enemy = enemy_q
+            .iter()
+            .filter(|(_, enemy_pos)| fov.visible_tiles.contains(enemy_pos))
+            .filter(|(_, enemy_pos)| {
+                DistanceAlg::Pythagoras.distance2d(*player_pos, **enemy_pos) < LEASH_DISTANCE
+            })
+            .min_by_key(|(_, enemy_pos)| DistanceAlg::Pythagoras.distance2d(*pos, **enemy_pos) as i32);
+
+        if let Some((_, enemy_pos)) = nearest_enemy {
+            step_towards(&pathing, entity, *pos, *enemy_pos, &mut move_events);
+        } else if DistanceAlg::Pythagoras.distance2d(*pos, *player_pos) > FOLLOW_DISTANCE {
+            step_towards(&pathing, entity, *pos, *player_pos, &mut move_events);
+        }
+    }
+}
+
+fn step_towards(
+    pathing: &PathingMap,
+    entity: Entity,
+    from: Point,
+    to: Point,
+    move_events: &mut EventWriter<WantsToMove>,
+) {
+    let map = pathing.map;
+    let path = a_star_search(map.point2d_to_index(from), map.point2d_to_index(to), pathing);
+    if path.success && path.steps.len() > 1 {
+        move_events.send(WantsToMove(entity, map.index_to_point2d(path.steps[1])));
+    }
+}
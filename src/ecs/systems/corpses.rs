@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+pub fn rot_corpses(
+    player: Res<Entity>,
+    mut commands: Commands,
+    mut corpse_q: Query<(Entity, &mut Corpse, &mut Naming, &mut Glyph, Option<&InBackpack>)>,
+) {
+    for (entity, mut corpse, mut name, mut glyph, backpack) in corpse_q.iter_mut() {
+        corpse.age += 1;
+        let carried = backpack.map_or(false, |b| b.owner == *player);
+
+        if corpse.age == CORPSE_ROT_TURNS {
+            name.0 = format!("rotten {} corpse", corpse.name);
+            glyph.color = ColorPair::new(OLIVE, BLACK);
+
+            if carried {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Loot)
+                    .append("Your")
+                    .item_name(&corpse.name)
+                    .append("corpse starts to smell.")
+                    .log();
+            }
+        } else if corpse.age >= CORPSE_DECAY_TURNS {
+            if carried {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Loot)
+                    .append("Your")
+                    .item_name(&name.0)
+                    .append("has rotted away.")
+                    .log();
+            }
+
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn butcher_corpses(
+    mut commands: Commands,
+    mut butcher_events: EventReader<WantsToButcher>,
+    corpse_q: Query<(&Corpse, &Point)>,
+) {
+    for WantsToButcher(_, target) in butcher_events.iter() {
+        if let Ok((corpse, pos)) = corpse_q.get(*target) {
+            if corpse.is_rotten() {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Loot)
+                    .append("The")
+                    .item_name(&corpse.name)
+                    .append("is too far gone to butcher.")
+                    .log();
+                continue;
+            }
+
+            let rations = crate::rng::roll_dice(1, 2);
+            for _ in 0..rations {
+                spawn_named_entity(&mut commands, RATIONS, SpawnType::AtPosition(*pos));
+            }
+
+            bo_logging::Logger::new()
+                .category(LogCategory::Loot)
+                .append("You butcher the")
+                .item_name(&corpse.name)
+                .append(format!("into {} rations.", rations))
+                .log();
+
+            commands.entity(*target).despawn_recursive();
+        }
+    }
+}
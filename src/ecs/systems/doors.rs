@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+pub fn has_key(actor: Entity, key: &str, backpack_q: &Query<(&InBackpack, &Naming)>) -> bool {
+    backpack_q.iter().any(|(backpack, name)| backpack.owner == actor && name.0 == key)
+}
+
+pub fn close_doors(
+    map: Res<Map>,
+    player: Res<Entity>,
+    mut commands: Commands,
+    mut close_events: EventReader<WantsToCloseDoor>,
+    mut door_q: Query<(&Point, &mut Glyph, &mut Door)>,
+    mut fov_q: Query<&mut FieldOfView>,
+) {
+    for WantsToCloseDoor(entity, door) in close_events.iter() {
+        if let Ok((pos, mut glyph, mut door_state)) = door_q.get_mut(*door) {
+            let mut occupied = false;
+            crate::spatial::for_each_tile_content(map.point2d_to_index(*pos), |e| {
+                if e != *door {
+                    occupied = true;
+                }
+            });
+
+            if occupied {
+                if *entity == *player {
+                    bo_logging::Logger::new().append("Something is in the way.").log();
+                }
+                continue;
+            }
+
+            door_state.0 = false;
+            glyph.glyph = to_cp437('+');
+            commands.entity(*door).insert(BlocksTile {}).insert(BlocksVisibility {});
+
+            // Everyone who could see through the doorway has to look again
+            fov_q.iter_mut().for_each(|mut fov| fov.is_dirty = true);
+        }
+    }
+}
+
+pub fn lock_doors(
+    mut lock_events: EventReader<WantsToLockDoor>,
+    mut door_q: Query<(&Door, &mut Lock)>,
+    backpack_q: Query<(&InBackpack, &Naming)>,
+) {
+    for WantsToLockDoor(entity, door) in lock_events.iter() {
+        if let Ok((door_state, mut lock)) = door_q.get_mut(*door) {
+            if door_state.0 {
+                bo_logging::Logger::new().append("You need to close the door first.").log();
+            } else if !has_key(*entity, &lock.key, &backpack_q) {
+                bo_logging::Logger::new().append("You need the").item_name(&lock.key).append("for this door.").log();
+            } else {
+                lock.locked = !lock.locked;
+                let action = if lock.locked { "You lock the door." } else { "You unlock the door." };
+                bo_logging::Logger::new().append(action).log();
+            }
+        }
+    }
+}
@@ -3,13 +3,18 @@ use crate::prelude::*;
 pub fn end_turn(
     state: Res<TurnState>,
     mut commands: Commands,
-    player_stats_q: Query<&CombatStats, With<Player>>,
+    player_stats_q: Query<(Entity, &CombatStats, Option<&Slowed>), With<Player>>,
 ) {
-    let stats = player_stats_q.single();
+    let (player, stats, slowed) = player_stats_q.single();
 
     let current_state = *state;
     let new_state = match current_state {
         TurnState::PlayerTurn => TurnState::AITurn,
+        // A slowed player loses their next action, so everyone else goes again
+        TurnState::AITurn if slowed.is_some() => {
+            commands.entity(player).remove::<Slowed>();
+            TurnState::AITurn
+        }
         TurnState::AITurn => TurnState::AwaitingInput,
         _ => current_state,
     };
@@ -3,11 +3,19 @@ use super::*;
 pub fn fov_system(mut map: ResMut<Map>, mut views: Query<(&Point, &mut FieldOfView, Option<&Player>)>) {
     for (pos, mut fov, player) in views.iter_mut() {
         if fov.is_dirty {
-            println!("fov_system: {:?}", pos);
+            log::trace!("fov_system: {:?}", pos);
             fov.is_dirty = false;
             fov.visible_tiles = field_of_view_set(*pos, fov.radius, map.as_ref());
 
             if player.is_some() {
+                // Darkness hides everything the player's light can't reach
+                if !map.lit {
+                    let map = map.as_ref();
+                    fov.visible_tiles.retain(|pt| {
+                        map.is_lit(*pt) || DistanceAlg::Pythagoras.distance2d(*pos, *pt) < 1.5
+                    });
+                }
+
                 map.clear_visible();
 
                 fov.visible_tiles.iter().for_each(|pt| {
@@ -17,3 +25,27 @@ pub fn fov_system(mut map: ResMut<Map>, mut views: Query<(&Point, &mut FieldOfVi
         };
     }
 }
+
+pub fn remember_entities(
+    mut map: ResMut<Map>,
+    glyph_q: Query<(&Point, &Glyph), (Without<Hidden>, Without<ParticleLifetime>, Without<Player>)>,
+) {
+    // Anything the player can see right now replaces what they remembered there
+    let seen = map
+        .remembered
+        .keys()
+        .filter(|idx| map.visible.get_bit(map.index_to_point2d(**idx)))
+        .copied()
+        .collect::<Vec<_>>();
+    seen.iter().for_each(|idx| {
+        map.remembered.remove(idx);
+    });
+
+    let mut entities = glyph_q.iter().filter(|(pos, _)| map.visible.get_bit(**pos)).collect::<Vec<_>>();
+    entities.sort_by(|&a, &b| b.1.render_order.cmp(&a.1.render_order));
+
+    for (pos, glyph) in entities {
+        let idx = map.point2d_to_index(*pos);
+        map.remembered.insert(idx, *glyph);
+    }
+}
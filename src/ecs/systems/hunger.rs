@@ -3,59 +3,94 @@ use crate::prelude::*;
 pub fn hunger_clock(
     state: Res<TurnState>,
     player_entity: Res<Entity>,
+    config: Res<RawHungerConfig>,
     mut hunger_q: Query<(Entity, &mut HungerClock)>,
 ) {
     for (entity, mut clock) in hunger_q.iter_mut() {
+        // The player's clock ticks on their turn, everyone else's on the AI turn
+        let is_player = entity == *player_entity;
         match *state {
-            TurnState::PlayerTurn => {
-                if entity != *player_entity {
-                    return;
-                }
-            }
-            TurnState::AITurn => {
-                if entity == *player_entity {
-                    return;
-                }
-            }
+            TurnState::PlayerTurn if !is_player => continue,
+            TurnState::AITurn if is_player => continue,
             _ => {}
         }
 
         clock.duration -= 1;
+        if clock.duration >= 1 {
+            continue;
+        }
 
-        if clock.duration < 1 {
-            match clock.state {
-                HungerState::WellFed => {
-                    clock.state = HungerState::Normal;
-                    clock.duration = 200;
-                    if entity == *player_entity {
-                        bo_logging::Logger::new().color(ORANGE).append("You are no longer well fed").log();
-                    }
-                }
-                HungerState::Normal => {
-                    clock.state = HungerState::Hungry;
-                    clock.duration = 200;
-                    if entity == *player_entity {
-                        bo_logging::Logger::new().color(ORANGE).append("You are hungry").log();
-                    }
-                }
-                HungerState::Hungry => {
-                    clock.state = HungerState::Starving;
-                    clock.duration = 200;
-                    if entity == *player_entity {
-                        bo_logging::Logger::new().color(RED).append("You are starving!").log();
-                    }
-                }
-                HungerState::Starving => {
-                    // Inflict damage from hunger
-                    if entity == *player_entity {
-                        bo_logging::Logger::new()
-                            .color(RED)
-                            .append("Your hunger pangs are getting painful! You suffer 1 hp damage.")
-                            .log();
-                    }
-
-                    add_effect(None, EffectType::Damage(1), Targets::Single(entity));
+        let next_state = match clock.state {
+            HungerState::WellFed => HungerState::Normal,
+            HungerState::Normal => HungerState::Hungry,
+            HungerState::Hungry | HungerState::Starving => HungerState::Starving,
+        };
+
+        if clock.state == HungerState::Starving {
+            // Inflict damage from hunger
+            if is_player {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Status)
+                    .color(RED)
+                    .append(&config.starving_message)
+                    .append(format!("You suffer {} hp damage.", config.starving_damage))
+                    .log();
+            }
+
+            add_effect(None, EffectType::Damage(config.starving_damage), Targets::Single(entity));
+        }
+
+        match config.stages.iter().find(|stage| stage.state == next_state) {
+            Some(stage) => {
+                if is_player && clock.state != next_state {
+                    let color = RGB::from_hex(&stage.color).unwrap_or_else(|_| RGB::named(ORANGE));
+                    bo_logging::Logger::new()
+                        .category(LogCategory::Status)
+                        .color(((color.r * 255.0) as u8, (color.g * 255.0) as u8, (color.b * 255.0) as u8))
+                        .append(&stage.message)
+                        .log();
                 }
+
+                // Starving doesn't wear off, it hurts every turn until something gets eaten
+                clock.duration = if next_state == HungerState::Starving { 0 } else { stage.duration };
+            }
+            None => {
+                log::warn!("No hunger stage in raws for [{:?}]", next_state);
+                clock.duration = config.default_nutrition;
+            }
+        }
+
+        clock.state = next_state;
+    }
+}
+
+pub fn sickness(
+    player_entity: Res<Entity>,
+    mut commands: Commands,
+    mut sick_q: Query<(Entity, &mut Sick)>,
+) {
+    for (entity, mut sick) in sick_q.iter_mut() {
+        sick.turns -= 1;
+
+        if crate::rng::roll_dice(1, 4) == 1 {
+            if entity == *player_entity {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Status)
+                    .color(ORANGE)
+                    .append("You retch. You suffer 1 hp damage.")
+                    .log();
+            }
+            add_effect(None, EffectType::Damage(1), Targets::Single(entity));
+        }
+
+        if sick.turns < 1 {
+            commands.entity(entity).remove::<Sick>();
+            if entity == *player_entity {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Status)
+                    .color(GREEN)
+                    .append("You feel better.")
+                    .log();
             }
         }
     }
@@ -13,7 +13,11 @@ pub fn item_collection(
         if entity == player_q.single() {
             let item_name = names_q.get(item).unwrap();
 
-            bo_logging::Logger::new().append("You pick up the").item_name(item_name.0.clone()).log();
+            bo_logging::Logger::new()
+                .category(LogCategory::Loot)
+                .append("You pick up the")
+                .item_name(item_name.0.clone())
+                .log();
         }
     }
 }
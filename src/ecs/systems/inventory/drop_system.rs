@@ -13,7 +13,11 @@ pub fn item_drop(
 
         if player.is_some() {
             let item_name = names_q.get(item).unwrap().0.clone();
-            bo_logging::Logger::new().append("You drop the").item_name(item_name).log();
+            bo_logging::Logger::new()
+                .category(LogCategory::Loot)
+                .append("You drop the")
+                .item_name(item_name)
+                .log();
         }
     }
 }
@@ -21,7 +21,11 @@ pub fn equip_use(
                 commands.entity(item_entity).remove::<Equipped>().insert(InBackpack::new(entity));
 
                 if entity == player_entity {
-                    bo_logging::Logger::new().append("You unequip").item_name(&name.0.clone()).log();
+                    bo_logging::Logger::new()
+                        .category(LogCategory::Loot)
+                        .append("You unequip")
+                        .item_name(&name.0.clone())
+                        .log();
                 }
             }
         }
@@ -32,7 +36,11 @@ pub fn equip_use(
 
         if entity == player_entity {
             let item_name = names_q.get(item).unwrap().0.clone();
-            bo_logging::Logger::new().append("You equip").item_name(item_name).log();
+            bo_logging::Logger::new()
+                .category(LogCategory::Loot)
+                .append("You equip")
+                .item_name(item_name)
+                .log();
         }
     }
 }
@@ -12,7 +12,11 @@ pub fn remove_item(
 
         if player_q.get(entity).is_ok() {
             let item_name = names_q.get(item).unwrap().0.clone();
-            bo_logging::Logger::new().append("You unequip").item_name(item_name).log();
+            bo_logging::Logger::new()
+                .category(LogCategory::Loot)
+                .append("You unequip")
+                .item_name(item_name)
+                .log();
         }
     }
 }
@@ -7,11 +7,27 @@ pub fn item_use(
     player_q: Query<Entity, With<Player>>,
     // Item Effects
     aoe_item_q: Query<&AreaOfEffect>,
+    particle_q: Query<(Option<&SpawnParticleLine>, Option<&SpawnParticleBurst>)>,
+    pos_q: Query<&Point>,
+    mut particle_builder: ResMut<ParticleBuilder>,
     mut wants_to_use: ResMut<Events<WantsToUseItem>>,
 ) {
     for WantsToUseItem(entity, item, target) in wants_to_use.drain() {
         let player_entity = player_q.single();
 
+        let fired = (target, pos_q.get(entity), particle_q.get(item));
+        if let (Some(target), Ok(start), Ok((line, burst))) = fired {
+            let radius = aoe_item_q.get(item).map_or(0, |aoe| aoe.radius);
+            particles::request_target_particles(
+                &mut particle_builder,
+                &map,
+                *start,
+                target,
+                line,
+                burst.map(|burst| (burst, radius)),
+            );
+        }
+
         add_effect(
             Some(entity),
             EffectType::ItemUse(item),
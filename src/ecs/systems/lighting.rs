@@ -0,0 +1,44 @@
+use super::*;
+
+pub fn lighting(
+    mut map: ResMut<Map>,
+    lights: Query<(Entity, &Point, &LightSource)>,
+    mut player_q: Query<&mut FieldOfView, With<Player>>,
+) {
+    let mut sources =
+        lights.iter().map(|(entity, pos, light)| (entity, *pos, light.radius)).collect::<Vec<_>>();
+    sources.sort_by_key(|(entity, _, _)| *entity);
+
+    // Nothing to redo until a light moves, appears or goes out
+    if sources == map.light_sources {
+        return;
+    }
+    map.light_sources = sources;
+    map.reset_light();
+
+    // Lit levels are already at full brightness
+    if map.lit {
+        return;
+    }
+
+    for (_, pos, light) in lights.iter() {
+        let radius = light.radius as f32;
+        let lit_tiles = field_of_view_set(*pos, light.radius, map.as_ref());
+
+        for pt in lit_tiles.iter() {
+            if !map.in_bounds(*pt) {
+                continue;
+            }
+
+            let idx = map.point2d_to_index(*pt);
+            let distance = DistanceAlg::Pythagoras.distance2d(*pos, *pt);
+            let intensity = (radius - distance) / radius;
+            map.add_light(idx, light.color, intensity.max(0.0));
+        }
+    }
+
+    // The player has to look again under the new light
+    if let Ok(mut fov) = player_q.get_single_mut() {
+        fov.is_dirty = true;
+    }
+}
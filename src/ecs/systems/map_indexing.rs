@@ -4,6 +4,8 @@ pub fn map_indexing(
     mut map: ResMut<Map>,
     dead_q: Query<Entity, Added<Dead>>,
     blocking_q: Query<(Entity, &Point, Option<&BlocksTile>, Option<&BlocksVisibility>)>,
+    revealed_traps_q: Query<&Point, (With<EntryTrigger>, Without<Hidden>)>,
+    door_q: Query<(&Point, &Door, Option<&Lock>)>,
 ) {
     if blocking_q.is_empty() && dead_q.is_empty() {
         return;
@@ -17,4 +19,15 @@ pub fn map_indexing(
         let idx = map.point2d_to_index(*pos);
         spatial::index_entity(entity, idx, blocker.is_some(), opaque.is_some());
     }
+
+    // Traps the player has found make paths through them costly for everyone
+    for pos in revealed_traps_q.iter() {
+        spatial::set_revealed_trap(map.point2d_to_index(*pos));
+    }
+
+    for (pos, door, lock) in door_q.iter() {
+        if !door.0 && !lock.map_or(false, |lock| lock.locked) {
+            spatial::set_closed_door(map.point2d_to_index(*pos));
+        }
+    }
 }
@@ -40,6 +40,8 @@ pub fn combat(
 
                     if damage == 0 {
                         bo_logging::Logger::new()
+                            .category(LogCategory::Combat)
+                            .source(attacker.to_bits(), &attacker_name.0)
                             .color(CYAN)
                             .append(&attacker_name.0)
                             .color(WHITE)
@@ -53,6 +55,8 @@ pub fn combat(
                         add_hit_miss_particle(victim);
                     } else {
                         bo_logging::Logger::new()
+                            .category(LogCategory::Combat)
+                            .source(attacker.to_bits(), &attacker_name.0)
                             .npc_name(&attacker_name.0)
                             .append("hits")
                             .npc_name(&target_name.0)
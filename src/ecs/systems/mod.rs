@@ -3,16 +3,23 @@ use bevy::ecs::schedule::ShouldRun;
 use crate::prelude::*;
 
 pub mod ai;
+pub mod companions;
+pub mod corpses;
+pub mod doors;
 pub mod end_turn;
 pub mod fov;
 pub mod hunger;
 pub mod inventory;
+pub mod lighting;
 pub mod map_indexing;
 pub mod melee_combat;
 pub mod movement;
 pub mod particles;
 pub mod player;
 pub mod render;
+pub mod spells;
+pub mod terrain;
+pub mod traps;
 pub mod trigger;
 
 pub struct TickingPlugin;
@@ -26,7 +33,8 @@ impl Plugin for TickingPlugin {
             AppState::Playing,
             SystemSet::new()
                 .with_system(map_indexing::map_indexing.label(StateLabel::Indexing))
-                .with_system(fov::fov_system.after(StateLabel::Indexing)),
+                .with_system(lighting::lighting.label(StateLabel::Lighting).after(StateLabel::Indexing))
+                .with_system(fov::fov_system.after(StateLabel::Lighting)),
         );
 
         app.add_system_set(
@@ -36,6 +44,14 @@ impl Plugin for TickingPlugin {
                 .with_system(fov::fov_system)
                 .into(),
         );
+
+        app.add_system_set(
+            ConditionSet::new()
+                .after(StateLabel::Fov)
+                .run_in_state(AppState::Playing)
+                .with_system(fov::remember_entities)
+                .into(),
+        );
     }
 }
 
@@ -69,7 +85,18 @@ impl Plugin for PlayerPlugin {
                 .with_system(movement::movement)
                 .with_system(melee_combat::combat)
                 .with_system(inventory::item_use)
+                .with_system(spells::cast_spell)
+                .with_system(spells::regenerate_mana)
                 .with_system(hunger::hunger_clock)
+                .with_system(hunger::sickness)
+                .with_system(corpses::butcher_corpses)
+                .with_system(companions::recruit)
+                .with_system(companions::swap_places)
+                .with_system(terrain::terrain_damage)
+                .with_system(traps::detect_hidden)
+                .with_system(traps::disarm_traps)
+                .with_system(doors::close_doors)
+                .with_system(doors::lock_doors)
                 .into(),
         )
         // Handle Actions
@@ -81,6 +108,7 @@ impl Plugin for PlayerPlugin {
                 .run_if_resource_equals(TurnState::PlayerTurn)
                 .with_system(map_indexing::map_indexing)
                 .with_system(trigger::triggers)
+                .with_system(lighting::lighting)
                 .into(),
         )
         .add_system_set_to_stage(
@@ -108,14 +136,17 @@ impl Plugin for PlayerPlugin {
 pub struct AIPlugin;
 impl Plugin for AIPlugin {
     fn build(&self, app: &mut App) {
+        // Monsters hold off until any projectile or blast on screen has played out
         // Generate Actions
         app.add_system_set_to_stage(
             AIStage::HandleAI,
             ConditionSet::new()
                 .run_in_state(AppState::Playing)
                 .run_if_resource_equals(TurnState::AITurn)
+                .run_if(particles::animations_finished)
                 .with_system(ai::monster_ai)
                 .with_system(ai::bystander_ai)
+                .with_system(companions::companion_ai)
                 .into(),
         )
         // Handle Actions
@@ -125,9 +156,12 @@ impl Plugin for AIPlugin {
                 .label(StateLabel::AIActions)
                 .run_in_state(AppState::Playing)
                 .run_if_resource_equals(TurnState::AITurn)
+                .run_if(particles::animations_finished)
                 .with_system(movement::movement)
                 .with_system(melee_combat::combat)
                 .with_system(hunger::hunger_clock)
+                .with_system(terrain::spread_tile_effects)
+                .with_system(corpses::rot_corpses)
                 .into(),
         )
         // Cleanup
@@ -138,8 +172,10 @@ impl Plugin for AIPlugin {
                 .after(StateLabel::AIActions)
                 .run_in_state(AppState::Playing)
                 .run_if_resource_equals(TurnState::AITurn)
+                .run_if(particles::animations_finished)
                 .with_system(map_indexing::map_indexing)
                 .with_system(trigger::triggers)
+                .with_system(lighting::lighting)
                 .into(),
         )
         .add_system_set_to_stage(
@@ -156,6 +192,7 @@ impl Plugin for AIPlugin {
                 .after(StateLabel::Indexing)
                 .run_in_state(AppState::Playing)
                 .run_if_resource_equals(TurnState::AITurn)
+                .run_if(particles::animations_finished)
                 .with_system(fov::fov_system)
                 .with_system(end_turn::end_turn)
                 .into(),
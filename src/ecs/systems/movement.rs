@@ -6,19 +6,50 @@ pub fn movement(
     mut commands: Commands,
     positions: Query<&Point>,
     mut fov_q: Query<&mut FieldOfView>,
-    mut door_q: Query<(Entity, &mut Glyph, &Point), With<Door>>,
+    mut door_q: Query<(Entity, &mut Glyph, &Point, &Door, Option<&mut Lock>)>,
+    door_openers_q: Query<&CanOpenDoors>,
+    swimmers_q: Query<(), Or<(With<CanSwim>, With<Flying>)>>,
+    backpack_q: Query<(&InBackpack, &Naming)>,
     mut move_events: ResMut<Events<WantsToMove>>,
     mut camera: ResMut<CameraView>,
 ) {
     for WantsToMove(entity, destination) in move_events.drain() {
-        door_q.iter_mut().filter(|(_, _, p)| **p == destination).for_each(|(door, mut glyph, _)| {
+        if let Some((door, mut glyph, _, _, lock)) =
+            door_q.iter_mut().find(|(_, _, p, door_state, _)| **p == destination && !door_state.0)
+        {
+            if !door_openers_q.contains(entity) {
+                continue;
+            }
+
+            if let Some(mut lock) = lock.filter(|lock| lock.locked) {
+                if !super::doors::has_key(entity, &lock.key, &backpack_q) {
+                    if entity == *player {
+                        bo_logging::Logger::new().append("The door is locked.").log();
+                    }
+                    continue;
+                }
+
+                lock.locked = false;
+                if entity == *player {
+                    bo_logging::Logger::new().append("You unlock the door with the").item_name(&lock.key).log();
+                }
+            }
+
             commands.entity(door).remove::<BlocksVisibility>().remove::<BlocksTile>().insert(Door(true));
             glyph.glyph = to_cp437('/');
             update_fov(entity, &mut fov_q);
-        });
+        }
 
-        if map.in_bounds(destination) && map.can_enter_tile(destination) {
-            commands.entity(entity).insert(destination);
+        let swimming = swimmers_q.contains(entity) && map.can_swim_into(destination);
+        if swimming || (map.in_bounds(destination) && map.can_enter_tile(destination)) {
+            commands.entity(entity).insert(destination).insert(EntityMoved {});
+
+            // Each point of cost over one is a chance of losing the next action getting through
+            let tile = &map.tiles[map.point2d_to_index(destination)];
+            let slow_chance = ((tile.cost - 1.0) * 100.0) as i32;
+            if tile.slows || crate::rng::range(0, 100) < slow_chance {
+                commands.entity(entity).insert(Slowed {});
+            }
 
             let pos = positions.get(entity).unwrap();
             let start_idx = map.point2d_to_index(*pos);
@@ -1,20 +1,35 @@
 use crate::prelude::*;
 
-pub fn particle_spawn_system(mut commands: Commands, mut particle_builder: ResMut<ParticleBuilder>) {
-    for ParticleRequest { pt, color, glyph, lifetime } in particle_builder.requests.iter() {
-        commands.spawn().insert_bundle(ParticleBundle::new(
-            *pt,
-            Glyph::new(*glyph, *color, RenderOrder::Particle),
-            ParticleLifetime::new(*lifetime, None),
+pub fn particle_spawn_system(
+    mut commands: Commands,
+    mut particle_builder: ResMut<ParticleBuilder>,
+    time: Res<Time>,
+) {
+    let delta = time.delta().as_millis() as f32;
+    let (ready, mut waiting): (Vec<_>, Vec<_>) =
+        particle_builder.requests.drain(..).partition(|request| request.delay_ms <= 0.0);
+
+    for ParticleRequest { pt, color, glyph, lifetime, animation, blocking, .. } in ready {
+        let mut particle = commands.spawn();
+        particle.insert_bundle(ParticleBundle::new(
+            pt,
+            Glyph::new(glyph, color, RenderOrder::Particle),
+            ParticleLifetime::new(lifetime, animation),
         ));
+
+        if blocking {
+            particle.insert(BlocksTurn);
+        }
     }
 
-    particle_builder.requests.clear();
+    // Anything delayed waits its turn for a later frame
+    waiting.iter_mut().for_each(|request| request.delay_ms -= delta);
+    particle_builder.requests = waiting;
 }
 
 pub fn update_particles(
     mut commands: Commands,
-    mut particles: Query<(Entity, &mut ParticleLifetime)>,
+    mut particles: Query<(Entity, &mut ParticleLifetime, &mut Point)>,
     time: Res<Time>,
 ) {
     if particles.is_empty() {
@@ -22,14 +37,54 @@ pub fn update_particles(
     }
 
     let delta = time.delta().as_millis() as f32;
-    for (entity, mut lifetime) in particles.iter_mut() {
+    for (entity, mut lifetime, mut pt) in particles.iter_mut() {
         lifetime.lifetime_ms -= delta;
         if lifetime.lifetime_ms < 0.0 {
             commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        // Moving particles take a step along their path every `step_time`
+        if let Some(animation) = lifetime.animation.as_mut() {
+            animation.timer += delta;
+            let last_step = animation.path.len() - 1;
+            while animation.timer >= animation.step_time && animation.current_step < last_step {
+                animation.timer -= animation.step_time;
+                animation.current_step += 1;
+            }
+            *pt = animation.path[animation.current_step];
         }
     }
 }
 
+/// True once no blocking particle is on screen or waiting to appear, or straight away
+/// when the settings say not to wait for them
+pub fn animations_finished(
+    settings: Res<AnimationSettings>,
+    particle_builder: Res<ParticleBuilder>,
+    blocking: Query<(), With<BlocksTurn>>,
+) -> bool {
+    !settings.wait_for_blocking || (blocking.is_empty() && !particle_builder.has_blocking_requests())
+}
+
+/// The projectile and blast for an item or spell fired at a target.
+/// The blast waits for the projectile to land.
+pub fn request_target_particles(
+    particle_builder: &mut ParticleBuilder,
+    map: &Map,
+    start: Point,
+    target: Point,
+    line: Option<&SpawnParticleLine>,
+    burst: Option<(&SpawnParticleBurst, i32)>,
+) {
+    let flight_ms = line.map_or(0.0, |line| particle_builder.request_line(start, target, line));
+
+    if let Some((burst, radius)) = burst {
+        let tiles = field_of_view_set(target, radius, map).into_iter().collect::<Vec<_>>();
+        particle_builder.request_burst(target, &tiles, burst, flight_ms);
+    }
+}
+
 pub struct ParticlePlugin;
 impl Plugin for ParticlePlugin {
     fn build(&self, app: &mut App) {
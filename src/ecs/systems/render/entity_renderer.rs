@@ -2,11 +2,20 @@ use crate::prelude::*;
 
 pub fn entity_render(
     (map, camera): (Res<Map>, Res<CameraView>),
-    glyph_q: Query<(&Point, &Glyph), (Without<Hidden>, Without<ParticleLifetime>, Without<Prop>)>,
+    glyph_q: Query<(&Point, &Glyph), (Without<Hidden>, Without<ParticleLifetime>)>,
 ) {
     let mut batch = DrawBatch::new();
     batch.target(LAYER_CHAR);
 
+    // Ghosts of entities last seen on tiles that are now out of sight
+    for (idx, glyph) in map.remembered.iter() {
+        let pt = map.index_to_point2d(*idx);
+        if camera.viewport.point_in_rect(pt) && !map.visible.get_bit(pt) {
+            let fg = glyph.color.fg.to_greyscale().lerp(RGBA::named(BLACK), 0.4);
+            batch.set(camera.world_to_screen(pt), ColorPair::new(fg, BLACK), glyph.glyph);
+        }
+    }
+
     let mut entities = glyph_q.iter().collect::<Vec<_>>();
     entities.sort_by(|&a, &b| b.1.render_order.cmp(&a.1.render_order));
 
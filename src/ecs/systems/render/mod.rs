@@ -1,3 +1,3 @@
 pub mod entity_renderer;
 pub mod map_renderer;
-// pub mod tooltips;
+pub mod tooltips;
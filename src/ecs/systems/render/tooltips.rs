@@ -6,61 +6,56 @@ pub fn render_tooltips(
     camera: Res<CameraView>,
     tooltip_q: Query<(&Point, &Naming, Option<&Description>, Option<&CombatStats>), Without<Hidden>>,
 ) {
-    let (min_x, _max_x, min_y, _max_y) = camera.get_screen_bounds();
-
-    let mut mouse_map_pos = ctx.mouse_pt;
-    let (mouse_x, mouse_y) = (mouse_map_pos.x, mouse_map_pos.y);
-    mouse_map_pos.x += min_x - 1;
-    mouse_map_pos.y += min_y - 1;
-
-    if !map.in_bounds(mouse_map_pos) || !map.visible.get_bit(mouse_map_pos) {
+    // The mouse is tracked on the map console, where a tile is one cell wide
+    let mouse_pt = ctx.mouse_pt;
+    let mouse_map_pos = camera.screen_to_world(mouse_pt);
+
+    if !camera.viewport.point_in_rect(mouse_map_pos)
+        || !map.in_bounds(mouse_map_pos)
+        || !map.visible.get_bit(mouse_map_pos)
+    {
         return;
     }
 
     let mut lines = Vec::new();
-    tooltip_q
-        .iter()
-        .filter(|(pos, _, _, _)| **pos == mouse_map_pos)
-        .for_each(|(pos, name, desc, stats)| {
-            if map.visible.get_bit(*pos) {
-                lines.push((CYAN, name.0.clone()));
+    tooltip_q.iter().filter(|(pos, _, _, _)| **pos == mouse_map_pos).for_each(|(_, name, desc, stats)| {
+        lines.push((CYAN, name.0.clone()));
+
+        if let Some(desc) = desc {
+            lines.push((GRAY, desc.0.clone()));
+        }
 
-                if let Some(desc) = desc {
-                    lines.push((GRAY, desc.0.clone()));
-                }
+        if let Some(stats) = stats {
+            let (health, color) = stats.health_estimate();
+            lines.push((color, health.to_string()));
+        }
+    });
 
-                if let Some(stats) = stats {
-                    lines.push((GRAY, format!("{}/{} hp", stats.hp, stats.max_hp)));
-                }
-            }
-        });
+    if lines.is_empty() {
+        return;
+    }
 
     let mut batch = DrawBatch::new();
     batch.target(LAYER_TEXT);
 
-    if !lines.is_empty() {
-        let height = lines.len() + 1;
-        let width = lines.iter().map(|s| s.1.len()).max().unwrap() + 2;
-
-        let tip_x = if mouse_map_pos.x < map.width as i32 / 2 {
-            i32::min((mouse_x) + 1, 111)
-        } else {
-            i32::max(0, (mouse_x) - (width as i32 + 1))
-        };
-        let tip_y = mouse_y / 2;
-        // if mouse_map_pos.y > map.height as i32 / 2 { mouse_y - height as i32 } else { mouse_y };
-
-        batch.draw_box(
-            Rect::with_size(tip_x, tip_y - (lines.len() / 2) as i32, width as i32, height as i32),
-            ColorPair::new(WHITE, BLACK),
-        );
-
-        let mut y = tip_y + 1 - (lines.len() / 2) as i32;
-        lines.iter().for_each(|s| {
-            safe_print_color(&mut batch, Point::new(tip_x + 1, y), &s.1, ColorPair::new(s.0, BLACK));
-            y += 1;
-        });
-    }
+    // ...while the text console has two cells to every tile
+    let text_pt = Point::new(mouse_pt.x * 2, mouse_pt.y);
+    let height = lines.len() as i32 + 1;
+    let width = lines.iter().map(|s| s.1.len()).max().unwrap() as i32 + 2;
+
+    // Sit beside the tile on whichever side has more room, and stay on screen vertically
+    let tip_x = if text_pt.x < MAP_PANEL.width() / 2 {
+        i32::min(text_pt.x + 3, UI_WIDTH - width - 1)
+    } else {
+        i32::max(0, text_pt.x - width - 2)
+    };
+    let tip_y = (text_pt.y - height / 2).clamp(0, UI_HEIGHT - height - 1);
+
+    batch.draw_box(Rect::with_size(tip_x, tip_y, width, height), ColorPair::new(WHITE, BLACK));
+    lines.iter().enumerate().for_each(|(i, s)| {
+        let pt = Point::new(tip_x + 1, tip_y + 1 + i as i32);
+        safe_print_color(&mut batch, pt, &s.1, ColorPair::new(s.0, BLACK));
+    });
 
     batch.submit(BATCH_TOOLTIPS).expect("Error batching tooltips");
 }
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+
+/// A point of mana comes back every this many turns
+const MANA_REGEN_TURNS: i32 = 4;
+
+pub fn cast_spell(
+    map: Res<Map>,
+    mut cast_events: EventReader<WantsToCastSpell>,
+    spell_q: Query<(&Naming, &SpellTemplate, Option<&AreaOfEffect>)>,
+    particle_q: Query<(Option<&SpawnParticleLine>, Option<&SpawnParticleBurst>)>,
+    pos_q: Query<&Point>,
+    mut mana_q: Query<&mut Mana>,
+    mut particle_builder: ResMut<ParticleBuilder>,
+) {
+    for WantsToCastSpell(caster, spell, target) in cast_events.iter() {
+        let (name, template, aoe) = match spell_q.get(*spell) {
+            Ok(spell) => spell,
+            Err(_) => continue,
+        };
+
+        // Anything without a mana pool can't pay for a spell at all
+        let mut mana = match mana_q.get_mut(*caster) {
+            Ok(mana) => mana,
+            Err(_) => continue,
+        };
+        if mana.current < template.mana_cost {
+            bo_logging::Logger::new()
+                .append("You don't have enough mana to cast")
+                .color(CYAN)
+                .append(&name.0)
+                .log();
+            continue;
+        }
+        mana.current -= template.mana_cost;
+
+        bo_logging::Logger::new()
+            .category(LogCategory::Combat)
+            .append("You cast")
+            .color(CYAN)
+            .append(&name.0)
+            .log();
+
+        let fired = (target, pos_q.get(*caster), particle_q.get(*spell));
+        if let (Some(target), Ok(start), Ok((line, burst))) = fired {
+            particles::request_target_particles(
+                &mut particle_builder,
+                &map,
+                *start,
+                *target,
+                line,
+                burst.map(|burst| (burst, aoe.map_or(0, |aoe| aoe.radius))),
+            );
+        }
+
+        add_effect(
+            Some(*caster),
+            EffectType::SpellUse(*spell),
+            match target {
+                None => Targets::Single(*caster),
+                Some(target) => match aoe {
+                    Some(aoe) => Targets::Tiles(aoe_tiles(&map, *target, aoe.radius)),
+                    None => Targets::Tile(map.point2d_to_index(*target)),
+                },
+            },
+        );
+    }
+}
+
+pub fn regenerate_mana(mut mana_q: Query<&mut Mana>) {
+    if bo_logging::get_event_count(TURN_DONE_EVENT) % MANA_REGEN_TURNS != 0 {
+        return;
+    }
+
+    for mut mana in mana_q.iter_mut() {
+        mana.current = i32::min(mana.max, mana.current + 1);
+    }
+}
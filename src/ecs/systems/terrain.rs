@@ -0,0 +1,48 @@
+use crate::prelude::*;
+
+const FIRE_DAMAGE: i32 = 4;
+const POISON_GAS_DAMAGE: i32 = 2;
+
+pub fn terrain_damage(
+    map: Res<Map>,
+    mut commands: Commands,
+    standing_q: Query<(Entity, &Point, Option<&Player>, Option<&Flying>), With<CombatStats>>,
+) {
+    for (entity, pos, player, flying) in standing_q.iter() {
+        let idx = map.point2d_to_index(*pos);
+        let tile = &map.tiles[idx];
+        if tile.damage > 0 {
+            add_effect(None, EffectType::Damage(tile.damage), Targets::Single(entity));
+        }
+
+        match map.tile_effect(idx) {
+            Some(TileEffectKind::Fire) => {
+                add_effect(None, EffectType::Damage(FIRE_DAMAGE), Targets::Single(entity));
+                if player.is_some() {
+                    bo_logging::Logger::new().category(LogCategory::Combat).append("You are burning!").log();
+                }
+            }
+            Some(TileEffectKind::PoisonGas) => {
+                add_effect(None, EffectType::Damage(POISON_GAS_DAMAGE), Targets::Single(entity));
+                if player.is_some() {
+                    bo_logging::Logger::new()
+                        .category(LogCategory::Combat)
+                        .append("You choke on the poison gas!")
+                        .log();
+                }
+            }
+            Some(TileEffectKind::Water) if flying.is_none() => {
+                commands.entity(entity).insert(Slowed {});
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Ages and spreads fire, gas and smoke once a round
+pub fn spread_tile_effects(mut map: ResMut<Map>, mut fov_q: Query<&mut FieldOfView>) {
+    if map.tick_tile_effects() {
+        // Smoke may have moved, so everyone needs to look again
+        fov_q.iter_mut().for_each(|mut fov| fov.is_dirty = true);
+    }
+}
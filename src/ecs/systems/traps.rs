@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+// One in N chance per turn of spotting each hidden entity in view
+const PASSIVE_DETECTION_CHANCE: i32 = 24;
+const SEARCH_DETECTION_CHANCE: i32 = 3;
+
+// Disarming succeeds on a d20 roll of at least this much; a natural 1 sets the trap off
+const DISARM_DIFFICULTY: i32 = 8;
+
+pub fn detect_hidden(
+    mut commands: Commands,
+    mut search_events: EventReader<WantsToSearch>,
+    player_q: Query<&FieldOfView, With<Player>>,
+    hidden_q: Query<(Entity, &Point, Option<&Naming>), With<Hidden>>,
+) {
+    let searching = search_events.iter().count() > 0;
+    let chance = if searching { SEARCH_DETECTION_CHANCE } else { PASSIVE_DETECTION_CHANCE };
+
+    let fov = match player_q.get_single() {
+        Ok(fov) => fov,
+        Err(_) => return,
+    };
+
+    let mut found_something = false;
+    for (entity, pos, name) in hidden_q.iter() {
+        if fov.visible_tiles.contains(pos) && crate::rng::roll_dice(1, chance) == 1 {
+            found_something = true;
+            commands.entity(entity).remove::<Hidden>();
+
+            if let Some(name) = name {
+                bo_logging::Logger::new().append("You spotted a").item_name(&name.0).log();
+            }
+        }
+    }
+
+    if searching && !found_something {
+        bo_logging::Logger::new().append("You search the area, but find nothing.").log();
+    }
+}
+
+pub fn disarm_traps(
+    mut commands: Commands,
+    map: Res<Map>,
+    mut disarm_events: EventReader<WantsToDisarm>,
+    positions_q: Query<&Point>,
+    names_q: Query<&Naming>,
+) {
+    for WantsToDisarm(entity, trap) in disarm_events.iter() {
+        let name = names_q.get(*trap).map(|n| n.0.clone()).unwrap_or_else(|_| "trap".to_string());
+
+        match crate::rng::roll_dice(1, 20) {
+            1 => {
+                bo_logging::Logger::new()
+                    .category(LogCategory::Combat)
+                    .append("You fumble and set off the")
+                    .item_name(&name)
+                    .log();
+
+                if let Ok(pos) = positions_q.get(*entity) {
+                    add_effect(
+                        Some(*entity),
+                        EffectType::TriggerFire(*trap),
+                        Targets::Tile(map.point2d_to_index(*pos)),
+                    );
+                }
+            }
+            roll if roll >= DISARM_DIFFICULTY => {
+                bo_logging::Logger::new().append("You disarm the").item_name(&name).log();
+                commands.entity(*trap).despawn();
+            }
+            _ => bo_logging::Logger::new().append("You fail to disarm the").item_name(&name).log(),
+        }
+    }
+}
@@ -2,16 +2,38 @@ use crate::prelude::*;
 
 pub fn triggers(
     map: Res<Map>,
+    player: Res<Entity>,
+    mut commands: Commands,
     area_of_effect: Query<&AreaOfEffect>,
-    positions_q: Query<(Entity, &Point)>,
-    triggers: Query<(Entity, Option<&Naming>, &Point), With<EntryTrigger>>,
+    moved_q: Query<(Entity, &Point, Option<&CombatStats>, Option<&Flying>), With<EntityMoved>>,
+    triggers: Query<(&EntryTrigger, Option<&Naming>)>,
 ) {
-    for (entity, position) in positions_q.iter() {
-        for (entity_id, name, _) in triggers.iter().filter(|(_, _, pos)| **pos == *position) {
-            if entity != entity_id {
+    for (entity, position, stats, flying) in moved_q.iter() {
+        commands.entity(entity).remove::<EntityMoved>();
+
+        crate::spatial::for_each_tile_content(map.point2d_to_index(*position), |entity_id| {
+            if entity == entity_id {
+                return;
+            }
+
+            if let Ok((trigger, name)) = triggers.get(entity_id) {
+                let allowed = trigger.filters.iter().all(|filter| match filter {
+                    TriggerFilter::Creatures => stats.is_some(),
+                    TriggerFilter::PlayerOnly => entity == *player,
+                    TriggerFilter::NotFlying => flying.is_none(),
+                });
+
+                if !allowed {
+                    return;
+                }
+
                 // We triggered it
                 if let Some(name) = name {
-                    bo_logging::Logger::new().item_name(&name.0).append("triggers!").log();
+                    bo_logging::Logger::new()
+                        .category(LogCategory::Combat)
+                        .item_name(&name.0)
+                        .append("triggers!")
+                        .log();
                 }
 
                 // Call the effects system
@@ -25,6 +47,6 @@ pub fn triggers(
                     },
                 );
             }
-        }
+        });
     }
 }
@@ -0,0 +1,361 @@
+use crate::prelude::*;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(target_os = "emscripten")]
+pub const KEYBINDINGS_FILENAME: &str = "/bload_oath/keybindings.ron";
+
+#[cfg(not(target_os = "emscripten"))]
+pub const KEYBINDINGS_FILENAME: &str = "keybindings.ron";
+
+lazy_static! {
+    static ref KEYBINDINGS: Mutex<KeyBindings> = Mutex::new(KeyBindings::default());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// Key names
+///////////////////////////////////////////////////////////////////////////////
+
+macro_rules! key_names {
+    ($($key:ident),* $(,)?) => {
+        /// The name a key goes by in the config file
+        pub fn key_name(key: VirtualKeyCode) -> Option<&'static str> {
+            match key {
+                $(VirtualKeyCode::$key => Some(stringify!($key)),)*
+                _ => None,
+            }
+        }
+
+        pub fn key_from_name(name: &str) -> Option<VirtualKeyCode> {
+            match name {
+                $(stringify!($key) => Some(VirtualKeyCode::$key),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+key_names!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9,
+    Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+    Up, Down, Left, Right, Home, End, PageUp, PageDown, Insert, Delete,
+    Escape, Return, Space, Tab, Back,
+    Apostrophe, Backslash, Comma, Equals, Grave, LBracket, Minus, Period, RBracket, Semicolon, Slash,
+);
+
+///////////////////////////////////////////////////////////////////////////////
+/// Presets
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPreset {
+    ViKeys,
+    Numpad,
+    Wasd,
+}
+
+impl KeyPreset {
+    pub const ALL: [KeyPreset; 3] = [KeyPreset::ViKeys, KeyPreset::Numpad, KeyPreset::Wasd];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyPreset::ViKeys => "vi-keys",
+            KeyPreset::Numpad => "Numpad",
+            KeyPreset::Wasd => "WASD",
+        }
+    }
+
+    pub fn bindings(&self) -> KeyBindings {
+        use VirtualKeyCode::*;
+
+        let mut bindings = KeyBindings::empty();
+        match self {
+            KeyPreset::ViKeys => {
+                bindings.bind_all(&[
+                    (GameKey::Up, K),
+                    (GameKey::Down, J),
+                    (GameKey::Left, H),
+                    (GameKey::Right, L),
+                    (GameKey::LeftUp, Y),
+                    (GameKey::RightUp, U),
+                    (GameKey::LeftDown, B),
+                    (GameKey::RightDown, N),
+                ]);
+                bindings.bind_all(&STANDARD_ACTIONS);
+            }
+            KeyPreset::Numpad => {
+                bindings.bind_all(&[
+                    (GameKey::Up, Numpad8),
+                    (GameKey::Down, Numpad2),
+                    (GameKey::Left, Numpad4),
+                    (GameKey::Right, Numpad6),
+                    (GameKey::LeftUp, Numpad7),
+                    (GameKey::RightUp, Numpad9),
+                    (GameKey::LeftDown, Numpad1),
+                    (GameKey::RightDown, Numpad3),
+                    (GameKey::SkipTurn, Numpad5),
+                ]);
+                bindings.bind_all(&STANDARD_ACTIONS);
+            }
+            // WASD takes over several of the usual action letters, so those actions move elsewhere
+            KeyPreset::Wasd => bindings.bind_all(&[
+                (GameKey::Up, W),
+                (GameKey::Down, S),
+                (GameKey::Left, A),
+                (GameKey::Right, D),
+                (GameKey::LeftUp, Q),
+                (GameKey::RightUp, E),
+                (GameKey::LeftDown, Z),
+                (GameKey::RightDown, C),
+                (GameKey::TakeStairs, Period),
+                (GameKey::Escape, Escape),
+                (GameKey::Select, Return),
+                (GameKey::SkipTurn, Space),
+                (GameKey::Pickup, G),
+                (GameKey::Inventory, I),
+                (GameKey::Drop, B),
+                (GameKey::Remove, R),
+                (GameKey::Apply, F),
+                (GameKey::Equip, T),
+                (GameKey::Search, H),
+                (GameKey::Disarm, N),
+                (GameKey::Butcher, J),
+                (GameKey::Orders, O),
+                (GameKey::Spellbook, M),
+                (GameKey::MessageLog, P),
+                (GameKey::CloseDoor, K),
+                (GameKey::LockDoor, X),
+                (GameKey::Look, Semicolon),
+                (GameKey::AutoExplore, V),
+            ]),
+        }
+
+        // Arrow keys always work
+        bindings.bind_all(&[
+            (GameKey::Up, Up),
+            (GameKey::Down, Down),
+            (GameKey::Left, Left),
+            (GameKey::Right, Right),
+        ]);
+        bindings
+    }
+}
+
+/// The action keys shared by the vi-keys and numpad presets
+const STANDARD_ACTIONS: [(GameKey, VirtualKeyCode); 20] = [
+    (GameKey::TakeStairs, VirtualKeyCode::Period),
+    (GameKey::Escape, VirtualKeyCode::Escape),
+    (GameKey::Select, VirtualKeyCode::Return),
+    (GameKey::SkipTurn, VirtualKeyCode::Space),
+    (GameKey::Pickup, VirtualKeyCode::G),
+    (GameKey::Inventory, VirtualKeyCode::I),
+    (GameKey::Drop, VirtualKeyCode::D),
+    (GameKey::Remove, VirtualKeyCode::R),
+    (GameKey::Apply, VirtualKeyCode::A),
+    (GameKey::Equip, VirtualKeyCode::E),
+    (GameKey::Search, VirtualKeyCode::S),
+    (GameKey::Disarm, VirtualKeyCode::T),
+    (GameKey::Butcher, VirtualKeyCode::Z),
+    (GameKey::Orders, VirtualKeyCode::O),
+    (GameKey::Spellbook, VirtualKeyCode::M),
+    (GameKey::MessageLog, VirtualKeyCode::P),
+    (GameKey::CloseDoor, VirtualKeyCode::C),
+    (GameKey::LockDoor, VirtualKeyCode::X),
+    (GameKey::Look, VirtualKeyCode::Semicolon),
+    (GameKey::AutoExplore, VirtualKeyCode::F),
+];
+
+/// Actions that always need a key, or there's no way to move or get out of a menu
+const REQUIRED_ACTIONS: [GameKey; 10] = [
+    GameKey::Escape,
+    GameKey::Select,
+    GameKey::Up,
+    GameKey::Down,
+    GameKey::Left,
+    GameKey::Right,
+    GameKey::LeftUp,
+    GameKey::RightUp,
+    GameKey::LeftDown,
+    GameKey::RightDown,
+];
+
+///////////////////////////////////////////////////////////////////////////////
+/// Bindings
+///////////////////////////////////////////////////////////////////////////////
+
+/// Which keys trigger each action. An action can have any number of keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: BTreeMap<GameKey, Vec<VirtualKeyCode>>,
+}
+
+/// Out of the box the vi-keys and the numpad both work, alongside the arrow keys
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let mut bindings = KeyPreset::ViKeys.bindings();
+        bindings.merge(&KeyPreset::Numpad.bindings());
+        bindings
+    }
+}
+
+impl KeyBindings {
+    pub fn empty() -> Self { Self { bindings: BTreeMap::new() } }
+
+    pub fn keys(&self, action: GameKey) -> &[VirtualKeyCode] {
+        self.bindings.get(&action).map_or(&[], |keys| keys.as_slice())
+    }
+
+    pub fn bind(&mut self, action: GameKey, key: VirtualKeyCode) {
+        let keys = self.bindings.entry(action).or_default();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    fn bind_all(&mut self, bindings: &[(GameKey, VirtualKeyCode)]) {
+        bindings.iter().for_each(|(action, key)| self.bind(*action, *key));
+    }
+
+    pub fn clear(&mut self, action: GameKey) { self.bindings.remove(&action); }
+
+    fn merge(&mut self, other: &KeyBindings) {
+        for (action, keys) in other.bindings.iter() {
+            keys.iter().for_each(|key| self.bind(*action, *key));
+        }
+    }
+
+    /// The action a key triggers. A key bound to several actions goes to the first of them.
+    pub fn action(&self, key: VirtualKeyCode) -> Option<GameKey> {
+        self.bindings.iter().find(|(_, keys)| keys.contains(&key)).map(|(action, _)| *action)
+    }
+
+    /// Every other action that shares a key with this one
+    pub fn conflicts_with(&self, action: GameKey) -> Vec<(VirtualKeyCode, GameKey)> {
+        let mut conflicts = Vec::new();
+        for key in self.keys(action).iter() {
+            for (other, keys) in self.bindings.iter() {
+                if *other != action && keys.contains(key) {
+                    conflicts.push((*key, *other));
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Every key bound to more than one action, with the actions it's bound to
+    pub fn conflicts(&self) -> Vec<(VirtualKeyCode, Vec<GameKey>)> {
+        let mut actions_by_key: HashMap<VirtualKeyCode, Vec<GameKey>> = HashMap::new();
+        for (action, keys) in self.bindings.iter() {
+            keys.iter().for_each(|key| actions_by_key.entry(*key).or_default().push(*action));
+        }
+
+        let mut conflicts =
+            actions_by_key.into_iter().filter(|(_, actions)| actions.len() > 1).collect::<Vec<_>>();
+        conflicts.sort();
+        conflicts
+    }
+
+    /// Required actions that have been left without a key
+    pub fn unbound_required(&self) -> Vec<GameKey> {
+        REQUIRED_ACTIONS.iter().filter(|action| self.keys(**action).is_empty()).copied().collect()
+    }
+
+    /// Every action is written out, cleared ones with no keys, so later versions can tell them
+    /// apart from actions that didn't exist yet
+    fn to_file(&self) -> KeyBindingsFile {
+        let bindings = GameKey::ALL
+            .iter()
+            .map(|action| {
+                let keys = self.keys(*action).iter().filter_map(|key| key_name(*key)).map(str::to_string);
+                (*action, keys.collect())
+            })
+            .collect();
+        KeyBindingsFile { bindings }
+    }
+
+    fn from_file(file: KeyBindingsFile) -> Self {
+        let defaults = KeyBindings::default();
+        let mut bindings = KeyBindings::empty();
+        for action in GameKey::ALL.iter() {
+            let names = match file.bindings.get(action) {
+                Some(names) => names,
+                // Actions added since the file was saved start out with their default keys
+                None => {
+                    defaults.keys(*action).iter().for_each(|key| bindings.bind(*action, *key));
+                    continue;
+                }
+            };
+
+            for name in names.iter() {
+                match key_from_name(name) {
+                    Some(key) => bindings.bind(*action, key),
+                    None => log::warn!("keybindings: unknown key {} for {:?}", name, action),
+                }
+            }
+        }
+        bindings
+    }
+}
+
+/// How the bindings are written to disk, with keys by name
+#[derive(Serialize, Deserialize)]
+struct KeyBindingsFile {
+    bindings: BTreeMap<GameKey, Vec<String>>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// Global bindings
+///////////////////////////////////////////////////////////////////////////////
+
+pub fn game_key(key: VirtualKeyCode) -> Option<GameKey> { KEYBINDINGS.lock().action(key) }
+
+pub fn current_bindings() -> KeyBindings { KEYBINDINGS.lock().clone() }
+
+pub fn set_bindings(bindings: KeyBindings) { *KEYBINDINGS.lock() = bindings; }
+
+/// Reads the bindings from the config file, falling back to the defaults if there isn't one
+pub fn load_keybindings() {
+    let bindings = read_keybindings().unwrap_or_else(|e| {
+        log::warn!("keybindings::load_keybindings: {}", e);
+        None
+    });
+
+    let mut bindings = bindings.unwrap_or_default();
+    for (key, actions) in bindings.conflicts() {
+        log::warn!("keybindings: {:?} is bound to more than one action: {:?}", key, actions);
+    }
+
+    // A hand-edited file could still leave the game without a way to move or back out
+    let defaults = KeyBindings::default();
+    for action in bindings.unbound_required() {
+        log::warn!("keybindings: {:?} has no keys, using the defaults", action);
+        defaults.keys(action).iter().for_each(|key| bindings.bind(action, *key));
+    }
+    set_bindings(bindings);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_keybindings() -> Result<Option<KeyBindings>, BoxedError> { Ok(None) }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_keybindings() -> Result<Option<KeyBindings>, BoxedError> {
+    let contents = match std::fs::read_to_string(KEYBINDINGS_FILENAME) {
+        Ok(contents) => contents,
+        Err(_) => return Ok(None),
+    };
+
+    let file = ron::from_str::<KeyBindingsFile>(&contents)?;
+    Ok(Some(KeyBindings::from_file(file)))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save_keybindings(_bindings: &KeyBindings) -> Result<(), BoxedError> { Ok(()) }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_keybindings(bindings: &KeyBindings) -> Result<(), BoxedError> {
+    let contents = ron::ser::to_string_pretty(&bindings.to_file(), ron::ser::PrettyConfig::new())?;
+    std::fs::write(KEYBINDINGS_FILENAME, contents)?;
+    Ok(())
+}
@@ -7,11 +7,13 @@ pub mod spawner;
 
 mod actions;
 mod ecs;
+mod keybindings;
 mod map;
 mod modes;
 mod random_table;
 mod render;
 mod rex_assets;
+mod run_history;
 mod utils;
 
 mod prelude {
@@ -35,6 +37,7 @@ mod prelude {
     // Local Crates
     pub use crate::impl_default;
     pub use crate::impl_new;
+    pub use crate::keybindings;
     pub use crate::raws;
     pub use crate::rng;
     pub use crate::spawner;
@@ -47,12 +50,15 @@ mod prelude {
     pub use crate::raws::*;
     pub use crate::render::*;
     pub use crate::rex_assets::*;
+    pub use crate::run_history::*;
     pub use crate::utils::*;
 
     pub type BoxedError = Box<dyn std::error::Error>;
     pub use crate::BracketContext;
 
     pub const MAP_GEN_TIMER: f32 = 100.0;
+    /// Oldest messages are dropped once the log holds this many
+    pub const MESSAGE_LOG_CAPACITY: usize = 500;
     pub const SHOW_BOUNDARIES: bool = true;
     pub const SHOW_MAPGEN_VISUALIZER: bool = false;
 
@@ -110,6 +116,8 @@ impl GameWorld {
         let mut app = App::new();
 
         raws::load_raws();
+        keybindings::load_keybindings();
+        bo_logging::set_log_capacity(MESSAGE_LOG_CAPACITY);
 
         // When building for WASM, print panics to the browser console
         #[cfg(target_arch = "wasm32")]
@@ -118,7 +126,15 @@ impl GameWorld {
         // Intent Events
         app.add_event::<WantsToMove>();
         app.add_event::<WantsToAttack>();
+        app.add_event::<WantsToSearch>();
+        app.add_event::<WantsToDisarm>();
+        app.add_event::<WantsToButcher>();
+        app.add_event::<WantsToRecruit>();
+        app.add_event::<WantsToSwapPlaces>();
+        app.add_event::<WantsToCloseDoor>();
+        app.add_event::<WantsToLockDoor>();
         app.add_event::<WantsToUseItem>();
+        app.add_event::<WantsToCastSpell>();
         // Item Events
         app.add_event::<WantsToDropItem>();
         app.add_event::<WantsToEquipItem>();
@@ -156,6 +172,7 @@ impl GameWorld {
 
         app.insert_resource(RexAssets::new());
         app.insert_resource(MenuMemory::new());
+        app.init_resource::<AnimationSettings>();
         app.add_loopless_state(AppState::MainMenu);
 
         app.add_plugin(MapGenPlugin);
@@ -170,7 +187,7 @@ impl GameWorld {
                 .with_system(ecs::render::map_renderer::map_render)
                 .with_system(ecs::render::entity_renderer::entity_render)
                 .with_system(ecs::render::entity_renderer::particle_render)
-                // .with_system(render::tooltips::render_tooltips)
+                .with_system(ecs::render::tooltips::render_tooltips)
                 .into(),
         );
 
@@ -201,8 +218,37 @@ embedded_resource!(VGA_FONT, "../resources/vga.png");
 embedded_resource!(TERMINAL_8X8_FONT, "../resources/terminal8x8.png");
 embedded_resource!(TERMINAL_10X16_FONT, "../resources/terminal10x16.png");
 
+/// Game messages are mirrored to this file, which is moved aside once it passes a megabyte
+#[cfg(not(target_arch = "wasm32"))]
+const GAME_LOG_FILENAME: &str = "game.log";
+#[cfg(not(target_arch = "wasm32"))]
+const GAME_LOG_MAX_BYTES: u64 = 1024 * 1024;
+#[cfg(not(target_arch = "wasm32"))]
+const GAME_LOG_FILES: usize = 3;
+
+/// Diagnostics go to env_logger natively and the browser console on wasm. Game messages follow them there,
+/// and natively also into a rotating log file.
+fn setup_logging() {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        env_logger::init();
+        bo_logging::add_sink(bo_logging::LogCrateSink);
+        bo_logging::add_sink(bo_logging::RotatingFileSink::new(
+            GAME_LOG_FILENAME,
+            GAME_LOG_MAX_BYTES,
+            GAME_LOG_FILES,
+        ));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        bo_logging::init_console_logger(log::LevelFilter::Info);
+        bo_logging::add_sink(bo_logging::ConsoleSink);
+    }
+}
+
 fn main() -> BError {
-    env_logger::init();
+    setup_logging();
 
     link_resource!(VGA_FONT, "resources/vga.png");
     link_resource!(TERMINAL_8X8_FONT, "resources/terminal8x8.png");
@@ -1,13 +1,24 @@
 use super::*;
 
-pub struct DoorPlacement {}
+pub struct DoorPlacement {
+    locked_vault: bool,
+}
 
 impl MetaMapBuilder for DoorPlacement {
-    fn build_map(&mut self, build_data: &mut BuilderMap) { self.doors(build_data); }
+    fn build_map(&mut self, build_data: &mut BuilderMap) {
+        self.doors(build_data);
+
+        if self.locked_vault {
+            self.lock_vault(build_data);
+        }
+    }
 }
 
 impl DoorPlacement {
-    pub fn new() -> Box<DoorPlacement> { Box::new(DoorPlacement {}) }
+    pub fn new() -> Box<DoorPlacement> { Box::new(DoorPlacement { locked_vault: false }) }
+
+    /// Also locks the door into a dead-end room as a vault door, with its key somewhere on this side of it
+    pub fn with_locked_vault() -> Box<DoorPlacement> { Box::new(DoorPlacement { locked_vault: true }) }
 
     fn door_possible(&self, build_data: &mut BuilderMap, idx: usize) -> bool {
         let mut blocked = false;
@@ -74,4 +85,93 @@ impl DoorPlacement {
             }
         }
     }
+
+    fn lock_vault(&mut self, build_data: &mut BuilderMap) {
+        let starting_pos = match build_data.starting_position {
+            Some(pos) => pos,
+            None => return,
+        };
+        let start_idx = build_data.map.xy_idx(starting_pos.x, starting_pos.y);
+        let reachable = self.distances_from(build_data, start_idx, None);
+
+        // A vault door has to seal off a dead end: somewhere behind it that holds a whole room (when the
+        // map has rooms) and doesn't hold the way down
+        let mut vaults = Vec::new();
+        for (spawn_idx, spawn) in build_data.spawn_list.iter().enumerate() {
+            if spawn.1 != DOOR {
+                continue;
+            }
+
+            let sealed = self.distances_from(build_data, start_idx, Some(spawn.0));
+            let cut_off = (0..sealed.len())
+                .filter(|i| *i != spawn.0 && reachable[*i] < std::f32::MAX && sealed[*i] == std::f32::MAX)
+                .collect::<Vec<_>>();
+
+            let tiles = &build_data.map.tiles;
+            let holds_exit = cut_off.iter().any(|i| tiles[*i].tile_type == TileType::DownStairs);
+            let holds_room = build_data.rooms.as_ref().map_or(true, |rooms| {
+                rooms.iter().any(|room| cut_off.contains(&build_data.map.point2d_to_index(room.center())))
+            });
+
+            if !cut_off.is_empty() && !holds_exit && holds_room {
+                vaults.push((spawn_idx, sealed));
+            }
+        }
+
+        if vaults.is_empty() {
+            return;
+        }
+
+        let vault = crate::rng::roll_dice(1, vaults.len() as i32) as usize - 1;
+        let (spawn_idx, sealed) = vaults.swap_remove(vault);
+
+        // The key goes somewhere the player can reach without going through the vault door
+        let key_spots = build_data
+            .map
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(i, tile)| {
+                tile.tile_type == TileType::Floor
+                    && *i != start_idx
+                    && sealed[*i] < std::f32::MAX
+                    && !build_data.spawn_list.iter().any(|spawn| spawn.0 == *i)
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        // If the key can't be reached, leave the door unlocked
+        if key_spots.is_empty() {
+            return;
+        }
+
+        let key_idx = key_spots[crate::rng::roll_dice(1, key_spots.len() as i32) as usize - 1];
+        build_data.spawn_list[spawn_idx].1 = VAULT_DOOR.to_string();
+        build_data.spawn_list.push((key_idx, VAULT_KEY.to_string()));
+    }
+
+    /// Walking distances from `start_idx`, with the tile at `sealed_idx` walled up if there is one
+    fn distances_from(
+        &self,
+        build_data: &BuilderMap,
+        start_idx: usize,
+        sealed_idx: Option<usize>,
+    ) -> Vec<f32> {
+        let mut sealed_map = build_data.map.clone();
+        if let Some(idx) = sealed_idx {
+            sealed_map.tiles[idx] = GameTile::wall();
+        }
+        crate::spatial::populate_blocked_from_map(&sealed_map);
+
+        let dijkstra_map = DijkstraMap::new(
+            build_data.map.width as usize,
+            build_data.map.height as usize,
+            &[start_idx],
+            &sealed_map,
+            1000.0,
+        );
+        crate::spatial::populate_blocked_from_map(&build_data.map);
+
+        dijkstra_map.map
+    }
 }
@@ -1,18 +1,15 @@
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-#[rustfmt::skip]
 pub mod prefab_levels;
-#[rustfmt::skip]
-pub mod prefab_rooms;
-#[rustfmt::skip]
 pub mod prefab_sections;
 
+/// Constant levels and sections name a prefab loaded into the raws
 #[derive(Eq, PartialEq, Copy, Clone)]
 pub enum PrefabMode {
     RexLevel { template: &'static str },
-    Constant { level: prefab_levels::PrefabLevel },
-    Sectional { section: prefab_sections::PrefabSection },
+    Constant { level: &'static str },
+    Sectional { section: &'static str },
     RoomVaults,
 }
 
@@ -20,6 +17,21 @@ pub struct PrefabBuilder {
     mode: PrefabMode,
 }
 
+struct PrefabLegend {
+    spawn_table: MasterTable,
+    glyphs: HashMap<char, RawLegendEntry>,
+}
+
+impl PrefabLegend {
+    fn new(depth: i32, overrides: &HashMap<char, RawLegendEntry>) -> Self {
+        let raws = RAWS.lock();
+        Self {
+            glyphs: raws::get_prefab_legend(&raws, overrides),
+            spawn_table: raws::get_spawn_table_for_depth(&raws, depth),
+        }
+    }
+}
+
 impl MetaMapBuilder for PrefabBuilder {
     fn build_map(&mut self, build_data: &mut BuilderMap) { self.build(build_data); }
 }
@@ -35,11 +47,11 @@ impl PrefabBuilder {
         Box::new(PrefabBuilder { mode: PrefabMode::RexLevel { template } })
     }
 
-    pub fn constant(level: prefab_levels::PrefabLevel) -> Box<PrefabBuilder> {
+    pub fn constant(level: &'static str) -> Box<PrefabBuilder> {
         Box::new(PrefabBuilder { mode: PrefabMode::Constant { level } })
     }
 
-    pub fn sectional(section: prefab_sections::PrefabSection) -> Box<PrefabBuilder> {
+    pub fn sectional(section: &'static str) -> Box<PrefabBuilder> {
         Box::new(PrefabBuilder { mode: PrefabMode::Sectional { section } })
     }
 
@@ -48,68 +60,47 @@ impl PrefabBuilder {
     fn build(&mut self, build_data: &mut BuilderMap) {
         match self.mode {
             PrefabMode::RexLevel { template } => self.load_rex_map(template, build_data),
-            PrefabMode::Constant { level } => self.load_ascii_map(&level, build_data),
-            PrefabMode::Sectional { section } => self.apply_sectional(&section, build_data),
+            PrefabMode::Constant { level } => self.load_ascii_map(level, build_data),
+            PrefabMode::Sectional { section } => self.apply_sectional(section, build_data),
             PrefabMode::RoomVaults => self.apply_room_vaults(build_data),
         }
         build_data.take_snapshot();
     }
 
-    fn char_to_map(&mut self, ch: char, idx: usize, build_data: &mut BuilderMap) {
+    fn char_to_map(&mut self, ch: char, idx: usize, legend: &PrefabLegend, build_data: &mut BuilderMap) {
         // Bounds check
         if idx >= build_data.map.tiles.len() - 1 {
             return;
         }
-        match ch {
-            ' ' => build_data.map.tiles[idx] = GameTile::floor(),
-            '#' => build_data.map.tiles[idx] = GameTile::wall(),
-            '≈' => build_data.map.tiles[idx] = GameTile::deep_water(),
-            '@' => {
-                let pt = build_data.map.index_to_point2d(idx);
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.starting_position = Some(pt);
-            }
-            '>' => build_data.map.tiles[idx] = GameTile::stairs_down(),
-            'e' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Dark Elf".to_string()));
-            }
-            'g' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Goblin".to_string()));
-            }
-            'o' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Orc".to_string()));
-            }
-            'O' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Orc Leader".to_string()));
-            }
-            '^' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Bear Trap".to_string()));
-            }
-            '%' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Rations".to_string()));
-            }
-            '!' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Health Potion".to_string()));
-            }
-            '☼' => {
-                build_data.map.tiles[idx] = GameTile::floor();
-                build_data.spawn_list.push((idx, "Watch Fire".to_string()));
+
+        let entry = match legend.glyphs.get(&ch) {
+            Some(entry) => entry,
+            None => {
+                log::warn!("Unknown glyph loading map: {}", ch);
+                return;
             }
-            _ => {
-                console::log(format!("Unknown glyph loading map: {}", (ch as u8) as char));
+        };
+
+        build_data.map.tiles[idx] = GameTile::from_type(entry.tile.unwrap_or(TileType::Floor));
+
+        if let Some(true) = entry.start {
+            build_data.starting_position = Some(build_data.map.index_to_point2d(idx));
+        }
+
+        if let Some(spawn) = &entry.spawn {
+            build_data.spawn_list.push((idx, spawn.clone()));
+        }
+
+        if let Some(category) = entry.table {
+            if let Some(spawn) = legend.spawn_table.roll_category(category) {
+                build_data.spawn_list.push((idx, spawn));
             }
         }
     }
 
     fn load_rex_map(&mut self, path: &str, build_data: &mut BuilderMap) {
         let xp_file = rex::XpFile::from_resource(path).unwrap();
+        let legend = PrefabLegend::new(build_data.map.depth, &HashMap::new());
 
         for layer in &xp_file.layers {
             for y in 0..layer.height {
@@ -118,34 +109,34 @@ impl PrefabBuilder {
                     if x < build_data.map.width as usize && y < build_data.map.height as usize {
                         let idx = build_data.map.xy_idx(x as i32, y as i32);
                         // We're doing some nasty casting to make it easier to type things like '#' in the match
-                        self.char_to_map(cell.ch as u8 as char, idx, build_data);
+                        self.char_to_map(to_char(cell.ch as u8), idx, &legend, build_data);
                     }
                 }
             }
         }
     }
 
-    fn read_ascii_to_vec(template: &str) -> Vec<char> {
-        let mut string_vec: Vec<char> = template.chars().filter(|a| *a != '\r' && *a != '\n').collect();
-        for c in string_vec.iter_mut() {
-            if *c as u8 == 160u8 {
-                *c = ' ';
-            }
+    fn get_prefab(name: &str) -> Option<RawPrefab> {
+        let prefab = raws::get_prefab(&RAWS.lock(), name);
+        if prefab.is_none() {
+            log::warn!("Unknown prefab: {}", name);
         }
-        string_vec
+        prefab
     }
 
-    fn load_ascii_map(&mut self, level: &prefab_levels::PrefabLevel, build_data: &mut BuilderMap) {
-        let string_vec = PrefabBuilder::read_ascii_to_vec(level.template);
+    fn load_ascii_map(&mut self, name: &str, build_data: &mut BuilderMap) {
+        let level = match PrefabBuilder::get_prefab(name) {
+            Some(level) => level,
+            None => return,
+        };
+        let legend = PrefabLegend::new(build_data.map.depth, &level.legend);
 
-        let mut i = 0;
         for ty in 0..level.height {
             for tx in 0..level.width {
                 if tx < build_data.map.width as usize && ty < build_data.map.height as usize {
                     let idx = build_data.map.xy_idx(tx as i32, ty as i32);
-                    self.char_to_map(string_vec[i], idx, build_data);
+                    self.char_to_map(level.tiles[ty * level.width + tx], idx, &legend, build_data);
                 }
-                i += 1;
             }
         }
     }
@@ -163,19 +154,22 @@ impl PrefabBuilder {
         build_data.take_snapshot();
     }
 
-    fn apply_sectional(&mut self, section: &prefab_sections::PrefabSection, build_data: &mut BuilderMap) {
-        use prefab_sections::*;
-
-        let string_vec = PrefabBuilder::read_ascii_to_vec(section.template);
+    fn apply_sectional(&mut self, name: &str, build_data: &mut BuilderMap) {
+        let section = match PrefabBuilder::get_prefab(name) {
+            Some(section) => section,
+            None => return,
+        };
+        let placement = section.placement.unwrap_or((HorizontalPlacement::Center, VerticalPlacement::Center));
+        let legend = PrefabLegend::new(build_data.map.depth, &section.legend);
 
         // Place the new section
-        let chunk_x = match section.placement.0 {
+        let chunk_x = match placement.0 {
             HorizontalPlacement::Left => 0,
             HorizontalPlacement::Center => (build_data.map.width / 2) - (section.width as i32 / 2),
             HorizontalPlacement::Right => (build_data.map.width - 1) - section.width as i32,
         };
 
-        let chunk_y = match section.placement.1 {
+        let chunk_y = match placement.1 {
             VerticalPlacement::Top => 0,
             VerticalPlacement::Center => (build_data.map.height / 2) - (section.height as i32 / 2),
             VerticalPlacement::Bottom => (build_data.map.height - 1) - section.height as i32,
@@ -192,7 +186,6 @@ impl PrefabBuilder {
             build_data,
         );
 
-        let mut i = 0;
         for ty in 0..section.height {
             for tx in 0..section.width {
                 if tx > 0
@@ -201,19 +194,14 @@ impl PrefabBuilder {
                     && ty > 0
                 {
                     let idx = build_data.map.xy_idx(tx as i32 + chunk_x, ty as i32 + chunk_y);
-                    if i < string_vec.len() {
-                        self.char_to_map(string_vec[i], idx, build_data);
-                    }
+                    self.char_to_map(section.tiles[ty * section.width + tx], idx, &legend, build_data);
                 }
-                i += 1;
             }
         }
         build_data.take_snapshot();
     }
 
     fn apply_room_vaults(&mut self, build_data: &mut BuilderMap) {
-        use prefab_rooms::*;
-
         // Apply the previous builder, and keep all entities it spawns (for now)
         self.apply_previous_iteration(|_x, _y| true, build_data);
 
@@ -223,14 +211,9 @@ impl PrefabBuilder {
             return;
         }
 
-        // Note that this is a place-holder and will be moved out of this function
-        let master_vault_list = vec![TOTALLY_NOT_A_TRAP, CHECKERBOARD, SILLY_SMILE];
-
         // Filter the vault list down to ones that are applicable to the current depth
-        let mut possible_vaults: Vec<&PrefabRoom> = master_vault_list
-            .iter()
-            .filter(|v| build_data.map.depth >= v.first_depth && build_data.map.depth <= v.last_depth)
-            .collect();
+        let mut possible_vaults =
+            raws::get_prefabs_for_depth(&RAWS.lock(), PrefabKind::Room, build_data.map.depth);
 
         if possible_vaults.is_empty() {
             return;
@@ -240,12 +223,10 @@ impl PrefabBuilder {
         let mut used_tiles: HashSet<usize> = HashSet::new();
 
         for _i in 0..n_vaults {
-            let vault_index = if possible_vaults.len() == 1 {
-                0
-            } else {
-                (crate::rng::roll_dice(1, possible_vaults.len() as i32) - 1) as usize
-            };
-            let vault = possible_vaults[vault_index];
+            let vault_index = PrefabBuilder::pick_vault(&possible_vaults);
+            let (vault_width, vault_height, vault_tiles) =
+                PrefabBuilder::orient_vault(&possible_vaults[vault_index]);
+            let legend = PrefabLegend::new(build_data.map.depth, &possible_vaults[vault_index].legend);
 
             // We'll make a list of places in which the vault could fit
             let mut vault_positions: Vec<Point> = Vec::new();
@@ -257,13 +238,13 @@ impl PrefabBuilder {
 
                 // Check that we won't overflow the map
                 if x > 1
-                    && (x + vault.width as i32) < build_data.map.width - 2
+                    && (x + vault_width as i32) < build_data.map.width - 2
                     && y > 1
-                    && (y + vault.height as i32) < build_data.map.height - 2
+                    && (y + vault_height as i32) < build_data.map.height - 2
                 {
                     let mut possible = true;
-                    for ty in 0..vault.height as i32 {
-                        for tx in 0..vault.width as i32 {
+                    for ty in 0..vault_height as i32 {
+                        for tx in 0..vault_width as i32 {
                             let idx = build_data.map.xy_idx(tx + x, ty + y);
                             if build_data.map.tiles[idx].tile_type != TileType::Floor {
                                 possible = false;
@@ -298,25 +279,21 @@ impl PrefabBuilder {
                 let chunk_y = pos.y;
 
                 let width = build_data.map.width; // The borrow checker really doesn't like it
-                let height = build_data.map.height; // when we access `self` inside the `retain`
                 build_data.spawn_list.retain(|e| {
                     let idx = e.0 as i32;
                     let x = idx % width;
-                    let y = idx / height;
+                    let y = idx / width;
                     x < chunk_x
-                        || x > chunk_x + vault.width as i32
+                        || x > chunk_x + vault_width as i32
                         || y < chunk_y
-                        || y > chunk_y + vault.height as i32
+                        || y > chunk_y + vault_height as i32
                 });
 
-                let string_vec = PrefabBuilder::read_ascii_to_vec(vault.template);
-                let mut i = 0;
-                for ty in 0..vault.height {
-                    for tx in 0..vault.width {
+                for ty in 0..vault_height {
+                    for tx in 0..vault_width {
                         let idx = build_data.map.xy_idx(tx as i32 + chunk_x, ty as i32 + chunk_y);
-                        self.char_to_map(string_vec[i], idx, build_data);
+                        self.char_to_map(vault_tiles[ty * vault_width + tx], idx, &legend, build_data);
                         used_tiles.insert(idx);
-                        i += 1;
                     }
                 }
                 build_data.take_snapshot();
@@ -325,4 +302,102 @@ impl PrefabBuilder {
             }
         }
     }
+
+    /// Picks a vault, weighted so higher weights turn up more often
+    fn pick_vault(vaults: &[RawPrefab]) -> usize {
+        let total = vaults.iter().map(|v| v.weight.max(1)).sum::<i32>();
+        let mut roll = crate::rng::roll_dice(1, total);
+
+        for (i, vault) in vaults.iter().enumerate() {
+            if roll <= vault.weight.max(1) {
+                return i;
+            }
+            roll -= vault.weight.max(1);
+        }
+
+        vaults.len() - 1
+    }
+
+    /// Randomly mirrors and rotates a vault, if it allows it. Returns the new width, height and tiles.
+    fn orient_vault(vault: &RawPrefab) -> (usize, usize, Vec<char>) {
+        let (mut width, mut height, mut tiles) = (vault.width, vault.height, vault.tiles.clone());
+
+        if vault.mirror && crate::rng::roll_dice(1, 2) == 1 {
+            tiles = mirror_tiles(width, height, &tiles);
+        }
+
+        if vault.rotate {
+            for _ in 1..crate::rng::roll_dice(1, 4) {
+                tiles = rotate_tiles(width, height, &tiles);
+                std::mem::swap(&mut width, &mut height);
+            }
+        }
+
+        (width, height, tiles)
+    }
+}
+
+/// Flips the tiles left to right
+fn mirror_tiles(width: usize, height: usize, tiles: &[char]) -> Vec<char> {
+    (0..height).flat_map(|y| (0..width).rev().map(move |x| tiles[y * width + x])).collect()
+}
+
+/// Turns the tiles 90 degrees clockwise, so the result is `height` wide and `width` tall
+fn rotate_tiles(width: usize, height: usize, tiles: &[char]) -> Vec<char> {
+    (0..width).flat_map(|y| (0..height).map(move |x| tiles[(height - 1 - x) * width + y])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault(rotate: bool, mirror: bool) -> RawPrefab {
+        let source = format!(
+            "name: Test\ntype: room\nsize: 3x2\nrotate: {}\nmirror: {}\n---\nabc\ndef\n",
+            rotate, mirror
+        );
+        RawPrefab::parse(&source, &[]).unwrap()
+    }
+
+    #[test]
+    fn rotates_clockwise() {
+        let tiles = vault(false, false).tiles;
+        assert_eq!(rotate_tiles(3, 2, &tiles), "daebfc".chars().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn four_turns_round_trip() {
+        let tiles = vault(false, false).tiles;
+        let turned = (0..4).fold((3, 2, tiles.clone()), |(width, height, tiles), _| {
+            (height, width, rotate_tiles(width, height, &tiles))
+        });
+        assert_eq!(turned, (3, 2, tiles));
+    }
+
+    #[test]
+    fn mirroring_twice_round_trips() {
+        let tiles = vault(false, false).tiles;
+        let mirrored = mirror_tiles(3, 2, &tiles);
+        assert_eq!(mirrored, "cbafed".chars().collect::<Vec<_>>());
+        assert_eq!(mirror_tiles(3, 2, &mirrored), tiles);
+    }
+
+    #[test]
+    fn fixed_vaults_keep_their_orientation() {
+        let vault = vault(false, false);
+        assert_eq!(PrefabBuilder::orient_vault(&vault), (3, 2, vault.tiles.clone()));
+    }
+
+    #[test]
+    fn oriented_vaults_keep_their_tiles() {
+        let vault = vault(true, true);
+        for _ in 0..20 {
+            let (width, height, tiles) = PrefabBuilder::orient_vault(&vault);
+            assert!((width, height) == (3, 2) || (width, height) == (2, 3));
+
+            let mut sorted = tiles.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, vault.tiles);
+        }
+    }
 }
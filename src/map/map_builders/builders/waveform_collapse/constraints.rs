@@ -65,10 +65,10 @@ pub fn build_patterns(
 
     // Dedupe
     if dedupe {
-        println!("Pre de-duplication, there are {} patterns", patterns.len());
+        log::debug!("Pre de-duplication, there are {} patterns", patterns.len());
         let set: HashSet<Vec<GameTile>> = patterns.drain(..).collect();
         patterns.extend(set.into_iter());
-        println!("There are {} patterns", patterns.len());
+        log::debug!("There are {} patterns", patterns.len());
     }
 
     patterns
@@ -199,7 +199,7 @@ impl Solver {
             }
 
             if possible_options.is_empty() {
-                println!("Oh no! It's not possible!");
+                log::debug!("Wave function collapse hit an impossible chunk, starting over");
                 self.possible = false;
                 return true;
             } else {
@@ -10,22 +10,10 @@ pub use town_people::*;
 pub fn town_builder(new_depth: i32, width: i32, height: i32) -> BuilderChain {
     let mut chain = BuilderChain::new(new_depth, width, height, "The Town of Bracketon");
     chain.start_with(TownBuilder::new());
+    chain.with_lighting(true);
     chain
 }
 
-#[derive(Debug)]
-enum BuildingTag {
-    Pub,
-    Temple,
-    Blacksmith,
-    Clothier,
-    Alchemist,
-    PlayerHouse,
-    Hovel,
-    Abandoned,
-    Unassigned,
-}
-
 pub struct TownBuilder {}
 
 impl InitialMapBuilder for TownBuilder {
@@ -62,6 +50,7 @@ impl TownBuilder {
         // Get Building sizes and grab largest
         let building_size = self.sort_buildings(&buildings);
         self.building_factory(build_data, &buildings, &building_size);
+        build_data.map.mark_tile_points_of_interest();
 
         self.spawn_dockers(build_data);
         self.spawn_townsfolk(build_data, &mut available_building_tiles);
@@ -101,7 +90,7 @@ impl TownBuilder {
             let y = crate::rng::roll_dice(1, build_data.height) - 1;
             for x in 2 + crate::rng::roll_dice(1, 6)..water_width[y as usize] + 4 {
                 let idx = build_data.map.xy_idx(x, y);
-                build_data.map.tiles[idx] = GameTile::wood_floor();
+                build_data.map.tiles[idx] = GameTile::bridge();
             }
         }
         build_data.take_snapshot();
@@ -289,6 +278,22 @@ impl TownBuilder {
         }
     }
 
+    fn mark_points_of_interest(
+        &mut self,
+        build_data: &mut BuilderMap,
+        building: &(i32, i32, i32, i32),
+        tag: BuildingTag,
+    ) {
+        for y in building.1..building.1 + building.3 {
+            for x in building.0..building.0 + building.2 {
+                let idx = build_data.map.xy_idx(x, y);
+                if build_data.map.tiles[idx].tile_type == TileType::WoodFloor {
+                    build_data.map.add_point_of_interest(tag, idx);
+                }
+            }
+        }
+    }
+
     fn sort_buildings(&mut self, buildings: &[(i32, i32, i32, i32)]) -> Vec<(usize, i32, BuildingTag)> {
         let mut building_size: Vec<(usize, i32, BuildingTag)> = Vec::new();
 
@@ -325,6 +330,7 @@ impl TownBuilder {
     ) {
         for (i, building) in buildings.iter().enumerate() {
             let build_type = &building_index[i].2;
+            self.mark_points_of_interest(build_data, building, *build_type);
 
             match build_type {
                 BuildingTag::Pub => self.build_pub(building, build_data),
@@ -34,7 +34,7 @@ impl TownBuilder {
 
         // Place other items
         let mut to_place: Vec<&str> =
-            vec![BARKEEP, SHADY_SALESMAN, PATRON, PATRON, KEG, TABLE, CHAIR, TABLE, CHAIR];
+            vec![BARKEEP, SHADY_SALESMAN, PATRON, PATRON, SELLSWORD, KEG, TABLE, CHAIR, TABLE, CHAIR];
         self.random_building_spawn(building, build_data, &mut to_place, player_idx);
     }
 
@@ -48,6 +48,8 @@ impl BuilderMap {
 
 pub struct BuilderChain {
     pub build_data: BuilderMap,
+    theme: Option<MapTheme>,
+    lit: Option<bool>,
     builders: Vec<Box<dyn MetaMapBuilder>>,
     starter: Option<Box<dyn InitialMapBuilder>>,
 }
@@ -55,6 +57,8 @@ pub struct BuilderChain {
 impl BuilderChain {
     pub fn new<S: ToString>(new_depth: i32, width: i32, height: i32, name: S) -> BuilderChain {
         BuilderChain {
+            theme: None,
+            lit: None,
             starter: None,
             builders: Vec::new(),
             build_data: BuilderMap {
@@ -81,6 +85,12 @@ impl BuilderChain {
         self.builders.push(metabuilder);
     }
 
+    /// Themes are applied once the chain has finished, since some builders swap out the map.
+    pub fn with_theme(&mut self, theme: MapTheme) { self.theme = Some(theme); }
+
+    /// Lit levels (towns, built dungeons) ignore light sources; dark levels (caves) need them to see.
+    pub fn with_lighting(&mut self, lit: bool) { self.lit = Some(lit); }
+
     pub fn build_map(&mut self) {
         match &mut self.starter {
             None => panic!("Cannot run a map builder chain without a starting build system"),
@@ -94,13 +104,25 @@ impl BuilderChain {
         for metabuilder in self.builders.iter_mut() {
             metabuilder.build_map(&mut self.build_data);
         }
+
+        if let Some(theme) = &self.theme {
+            self.build_data.map.theme = theme.clone();
+        }
+
+        if let Some(lit) = self.lit {
+            self.build_data.map.lit = lit;
+            self.build_data.map.reset_light();
+        }
     }
 }
 
 pub fn level_builder(new_depth: i32, width: i32, height: i32) -> BuilderChain {
-    console::log(format!("Depth: {}", new_depth));
-    match new_depth {
+    log::info!("Building level for depth {}", new_depth);
+    let mut chain = match new_depth {
         1 => town_builder(new_depth, width, height),
         _ => random_builder(new_depth, width, height),
-    }
+    };
+
+    chain.with_theme(raws::get_theme_for_depth(&RAWS.lock(), new_depth));
+    chain
 }
@@ -37,7 +37,10 @@ fn random_shape_builder(builder: &mut BuilderChain) {
         _ => builder.start_with(PrefabBuilder::constant(prefab_levels::WFC_POPULATED)),
     }
 
-    println!("Random shape start with: {}", builder_roll);
+    log::debug!("Random shape start with: {}", builder_roll);
+
+    // Caves and other natural shapes are pitch black
+    builder.with_lighting(false);
 
     // Set the start to the center and cull
     builder.with(AreaStartingPosition::new(XStart::CENTER, YStart::CENTER));
@@ -51,7 +54,7 @@ fn random_shape_builder(builder: &mut BuilderChain) {
     builder.with(VoronoiSpawning::new());
     builder.with(DistantExit::new());
 
-    println!("random_shape_builder veroni");
+    log::debug!("random_shape_builder voronoi");
 }
 
 fn random_room_builder(builder: &mut BuilderChain) {
@@ -62,7 +65,8 @@ fn random_room_builder(builder: &mut BuilderChain) {
         _ => builder.start_with(BspInteriorBuilder::new()),
     }
 
-    println!("Random Room start with: {}", build_roll);
+    log::debug!("Random Room start with: {}", build_roll);
+    builder.with_lighting(true);
 
     // BSP Interior still makes holes in the walls
     if build_roll != 3 {
@@ -120,7 +124,7 @@ fn random_room_builder(builder: &mut BuilderChain) {
         _ => builder.with(VoronoiSpawning::new()),
     }
 
-    println!("Random Room spawning: {}", spawn_roll);
+    log::debug!("Random Room spawning: {}", spawn_roll);
 }
 
 pub fn random_builder(new_depth: i32, width: i32, height: i32) -> BuilderChain {
@@ -144,7 +148,11 @@ pub fn random_builder(new_depth: i32, width: i32, height: i32) -> BuilderChain {
         builder.with(DistantExit::new());
     }
 
-    builder.with(DoorPlacement::new());
+    if crate::rng::roll_dice(1, 4) == 1 {
+        builder.with(DoorPlacement::with_locked_vault());
+    } else {
+        builder.with(DoorPlacement::new());
+    }
     builder.with(WallBoundaries::new());
     builder.with(PrefabBuilder::vaults());
 
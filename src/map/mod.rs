@@ -8,25 +8,52 @@ pub mod spatial;
 
 mod bitgrid;
 mod dungeon;
+mod pathing;
+mod points_of_interest;
 mod themes;
+mod tile_effects;
 mod tiletype;
 
 pub use bitgrid::*;
 pub use dungeon::*;
 pub use map_builders::BuilderMap;
+pub use pathing::*;
+pub use points_of_interest::*;
 pub use themes::*;
+pub use tile_effects::*;
 pub use tiletype::*;
 
+/// Tiles on dark levels need at least this much light in any channel to be seen
+pub const MIN_LIGHT_LEVEL: f32 = 0.1;
+
+/// Extra pathing cost for stepping onto a trap the player has revealed. Monsters don't track
+/// what they know, so every pather steers around the traps the player has found
+const REVEALED_TRAP_COST: f32 = 10.0;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Map {
     pub width: i32,
     pub height: i32,
     pub depth: i32,
     pub name: String,
+    pub theme: MapTheme,
+    pub lit: bool,
     pub visible: BitGrid,
     pub revealed: BitGrid,
     pub tiles: Vec<GameTile>,
     pub bloodstains: HashMap<usize, RGB>,
+    pub tile_effects: HashMap<usize, TileEffect>,
+    /// Floor tiles of each town building
+    pub points_of_interest: HashMap<BuildingTag, Vec<usize>>,
+    /// Every tile of each type, for schedules that send townsfolk to a kind of tile
+    pub tile_points_of_interest: HashMap<TileType, Vec<usize>>,
+    /// Last glyph the player saw on each tile that is now out of sight
+    pub remembered: HashMap<usize, Glyph>,
+    #[serde(skip)]
+    pub light: Vec<RGB>,
+    /// The light sources `light` was worked out from, so it's only redone when one of them changes
+    #[serde(skip)]
+    pub light_sources: Vec<(Entity, Point, i32)>,
 }
 
 impl Map {
@@ -40,6 +67,8 @@ impl Map {
         crate::spatial::clear_content_index();
         crate::spatial::clear_blocked();
         crate::spatial::clear_opaque();
+        crate::spatial::clear_revealed_traps();
+        crate::spatial::clear_closed_doors();
     }
 
     pub fn clear_visible(&mut self) { self.visible.zero_out_bits(); }
@@ -51,11 +80,46 @@ impl Map {
         }
     }
 
+    /// Resets the light layer to the level's ambient light: full white when lit, pitch black when dark.
+    pub fn reset_light(&mut self) {
+        let ambient = if self.lit { RGB::named(WHITE) } else { RGB::named(BLACK) };
+        self.light = vec![ambient; self.tiles.len()];
+    }
+
+    pub fn add_light(&mut self, idx: usize, color: RGB, intensity: f32) {
+        if let Some(light) = self.light.get_mut(idx) {
+            light.r = (light.r + color.r * intensity).min(1.0);
+            light.g = (light.g + color.g * intensity).min(1.0);
+            light.b = (light.b + color.b * intensity).min(1.0);
+        }
+    }
+
+    pub fn is_lit(&self, pt: Point) -> bool {
+        if self.lit {
+            return true;
+        }
+
+        match self.light.get(self.point2d_to_index(pt)) {
+            Some(light) => light.r.max(light.g).max(light.b) >= MIN_LIGHT_LEVEL,
+            None => false,
+        }
+    }
+
     pub fn can_enter_tile(&self, pt: Point) -> bool {
         let idx = self.point2d_to_index(pt);
         self.in_bounds(pt) && !crate::spatial::is_blocked(idx)
     }
 
+    /// Deep water and the like, which only swimmers and fliers can cross
+    pub fn can_swim_into(&self, pt: Point) -> bool {
+        if !self.in_bounds(pt) {
+            return false;
+        }
+
+        let idx = self.point2d_to_index(pt);
+        self.tiles[idx].swimmable && !crate::spatial::is_blocked_by_entity(idx)
+    }
+
     /// Generates an empty map, consisting entirely of solid walls
     pub fn new<S: ToString>(new_depth: i32, width: i32, height: i32, name: S) -> Map {
         let map_tile_count = (width * height) as usize;
@@ -66,7 +130,15 @@ impl Map {
             height,
             depth: new_depth,
             name: name.to_string(),
+            theme: MapTheme::default(),
+            lit: true,
+            light: vec![RGB::named(WHITE); map_tile_count],
+            light_sources: Vec::new(),
             bloodstains: HashMap::new(),
+            tile_effects: HashMap::new(),
+            points_of_interest: HashMap::new(),
+            tile_points_of_interest: HashMap::new(),
+            remembered: HashMap::new(),
             visible: BitGrid::new(width, height),
             revealed: BitGrid::new(width, height),
             tiles: vec![GameTile::wall(); map_tile_count],
@@ -74,36 +146,41 @@ impl Map {
     }
 
     pub fn tile_glyph(&self, idx: usize) -> (FontCharType, ColorPair) {
-        let tile = &self.tiles[idx];
-        let (glyph, mut color) = match self.depth {
-            3 => tile.get_limestone_glyph(),
-            2 => tile.get_forest_glyph(),
-            _ => tile.get_tile_glyph_default(self, idx),
-        };
+        let (glyph, mut color) = self.theme.tile_glyph(self, idx);
 
         if self.bloodstains.contains_key(&idx) {
             color.bg = (*self.bloodstains.get(&idx).unwrap()).into();
         }
 
+        if let Some(kind) = self.tile_effect(idx) {
+            color.bg = kind.color().into();
+        }
+
         if !self.visible.get_bit(self.index_to_point2d(idx)) {
             color.fg = color.fg.to_greyscale();
-            color.bg = RGBA::from_f32(0., 0., 0., 0.); // Don't show stains out of visual range
+            color.bg = RGBA::from_f32(0., 0., 0., 0.); // Don't show stains or effects out of visual range
+        } else if let Some(light) = self.light.get(idx).filter(|_| !self.lit) {
+            // Keep a floor of brightness so dimly lit tiles are still readable
+            let tint = |c: f32, l: f32| c * (0.25 + l * 0.75);
+            color.fg = RGBA::from_f32(
+                tint(color.fg.r, light.r),
+                tint(color.fg.g, light.g),
+                tint(color.fg.b, light.b),
+                color.fg.a,
+            );
         }
 
         (glyph, color)
     }
 
-    fn valid_exit(&self, loc: Point, delta: Point) -> Option<usize> {
-        let destination = loc + delta;
-        if self.in_bounds(destination) {
-            if self.can_enter_tile(destination) {
-                let idx = self.point2d_to_index(destination);
-                Some(idx)
-            } else {
-                None
-            }
+    /// The cost of stepping onto a tile. Revealed traps are still walkable, but pathing steers around them
+    /// when it can
+    fn exit_cost(&self, idx: usize) -> f32 {
+        let cost = self.tiles[idx].cost;
+        if crate::spatial::is_revealed_trap(idx) {
+            cost + REVEALED_TRAP_COST
         } else {
-            None
+            cost
         }
     }
 }
@@ -126,25 +203,9 @@ impl BaseMap for Map {
         }
     }
 
+    /// Plain map paths only cross open ground, see `PathingMap` for pathers that can do more
     fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
-        let mut exits = SmallVec::new();
-        let location = self.index_to_point2d(idx);
-        let tt = self.tiles[idx];
-
-
-        // Cardinals
-        if let Some(idx) = self.valid_exit(location, Point::new(-1, 0)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(1, 0)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(0, -1)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(0, 1)) { exits.push((idx, tt.cost)) }
-
-        // Diagonals
-        if let Some(idx) = self.valid_exit(location, Point::new(-1, -1)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(1, -1)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(-1, 1)) { exits.push((idx, tt.cost)) }
-        if let Some(idx) = self.valid_exit(location, Point::new(1, 1)) { exits.push((idx, tt.cost)) }
-
-        exits
+        self.exits_for(idx, MoveAbilities::default())
     }
 
     fn get_pathing_distance(&self, idx1:usize, idx2:usize) -> f32 {
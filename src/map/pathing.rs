@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/// What a creature can get through besides open ground when it works out a path
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MoveAbilities {
+    /// Closed doors that aren't locked are opened on the way through
+    pub opens_doors: bool,
+    /// Swimmable tiles too deep to walk through can be crossed, by swimming or flying over them
+    pub swims: bool,
+}
+
+impl MoveAbilities {
+    pub fn new(opens_doors: bool, swims: bool) -> Self { Self { opens_doors, swims } }
+}
+
+/// The map as one particular pather sees it. Paths on the bare `Map` only cross open ground.
+pub struct PathingMap<'a> {
+    pub map: &'a Map,
+    pub abilities: MoveAbilities,
+}
+
+impl<'a> PathingMap<'a> {
+    pub fn new(map: &'a Map, abilities: MoveAbilities) -> Self { Self { map, abilities } }
+}
+
+impl BaseMap for PathingMap<'_> {
+    fn is_opaque(&self, idx: usize) -> bool { self.map.is_opaque(idx) }
+
+    fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+        self.map.exits_for(idx, self.abilities)
+    }
+
+    fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+        self.map.get_pathing_distance(idx1, idx2)
+    }
+}
+
+/// Cardinals first, then diagonals
+const EXIT_DELTAS: [(i32, i32); 8] = [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+impl Map {
+    pub fn exits_for(&self, idx: usize, abilities: MoveAbilities) -> SmallVec<[(usize, f32); 10]> {
+        let location = self.index_to_point2d(idx);
+
+        EXIT_DELTAS
+            .iter()
+            .filter_map(|(dx, dy)| self.valid_exit(location, Point::new(*dx, *dy), abilities))
+            .map(|exit| (exit, self.exit_cost(exit)))
+            .collect()
+    }
+
+    /// Open ground is an exit for everyone, closed doors and deep water only for pathers that can get
+    /// through them
+    fn valid_exit(&self, loc: Point, delta: Point, abilities: MoveAbilities) -> Option<usize> {
+        let destination = loc + delta;
+        if !self.in_bounds(destination) {
+            return None;
+        }
+
+        let idx = self.point2d_to_index(destination);
+        let door = abilities.opens_doors && crate::spatial::is_closed_door(idx);
+        let water = abilities.swims && self.can_swim_into(destination);
+        if self.can_enter_tile(destination) || door || water {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum BuildingTag {
+    Pub,
+    Temple,
+    Blacksmith,
+    Clothier,
+    Alchemist,
+    PlayerHouse,
+    Hovel,
+    Abandoned,
+    Unassigned,
+}
+
+impl Map {
+    /// Marks a tile as belonging to a building, so townsfolk know where to go
+    pub fn add_point_of_interest(&mut self, tag: BuildingTag, idx: usize) {
+        self.points_of_interest.entry(tag).or_insert_with(Vec::new).push(idx);
+    }
+
+    pub fn points_of_interest(&self, tag: BuildingTag) -> &[usize] {
+        self.points_of_interest.get(&tag).map_or(&[], |tiles| tiles.as_slice())
+    }
+
+    /// Indexes tiles by type once the level is laid out, so schedules don't have to search the map
+    pub fn mark_tile_points_of_interest(&mut self) {
+        self.tile_points_of_interest.clear();
+        for (idx, tile) in self.tiles.iter().enumerate() {
+            self.tile_points_of_interest.entry(tile.tile_type).or_insert_with(Vec::new).push(idx);
+        }
+    }
+
+    pub fn tile_points_of_interest(&self, tile_type: TileType) -> &[usize] {
+        self.tile_points_of_interest.get(&tile_type).map_or(&[], |tiles| tiles.as_slice())
+    }
+}
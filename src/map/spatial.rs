@@ -6,12 +6,22 @@ struct SpatialMap {
     height: i32,
     opaque: Vec<bool>,
     blocked: Vec<(bool, bool)>,
+    revealed_traps: Vec<bool>,
+    closed_doors: Vec<bool>,
     tile_content: Vec<Vec<(Entity, bool, bool)>>,
 }
 
 impl SpatialMap {
     fn new() -> Self {
-        Self { width: 0, height: 0, blocked: Vec::new(), tile_content: Vec::new(), opaque: Vec::new() }
+        Self {
+            width: 0,
+            height: 0,
+            blocked: Vec::new(),
+            revealed_traps: Vec::new(),
+            closed_doors: Vec::new(),
+            tile_content: Vec::new(),
+            opaque: Vec::new(),
+        }
     }
 
     fn _xy_idx(&self, x: i32, y: i32) -> usize { (y as usize * self.width as usize) + x as usize }
@@ -34,6 +44,8 @@ pub fn set_size(width: i32, height: i32) {
     let map_tile_count = (width * height) as usize;
     lock.opaque = vec![true; map_tile_count];
     lock.blocked = vec![(false, false); map_tile_count];
+    lock.revealed_traps = vec![false; map_tile_count];
+    lock.closed_doors = vec![false; map_tile_count];
     lock.tile_content = vec![Vec::new(); map_tile_count];
 }
 
@@ -134,6 +146,12 @@ pub fn is_blocked(idx: usize) -> bool {
     lock.blocked[idx].0 || lock.blocked[idx].1
 }
 
+/// Blocked by something standing there, ignoring the terrain
+pub fn is_blocked_by_entity(idx: usize) -> bool {
+    let lock = SPATIAL_MAP.lock();
+    lock.blocked[idx].1
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 /// Opaque
 ///////////////////////////////////////////////////////////////////////////////
@@ -148,7 +166,7 @@ pub fn clear_opaque() {
 pub fn populate_opaque_from_map(map: &Map) {
     let mut lock = SPATIAL_MAP.lock();
     for (i, tile) in map.tiles.iter().enumerate() {
-        lock.opaque[i] = tile.opaque;
+        lock.opaque[i] = tile.opaque || map.effect_blocks_vision(i);
     }
 }
 
@@ -157,6 +175,49 @@ pub fn is_opaque(idx: usize) -> bool {
     lock.opaque[idx]
 }
 
+///////////////////////////////////////////////////////////////////////////////
+/// Revealed Traps
+///////////////////////////////////////////////////////////////////////////////
+
+pub fn clear_revealed_traps() {
+    let mut lock = SPATIAL_MAP.lock();
+    lock.revealed_traps.iter_mut().for_each(|t| {
+        *t = false;
+    });
+}
+
+pub fn set_revealed_trap(idx: usize) {
+    let mut lock = SPATIAL_MAP.lock();
+    lock.revealed_traps[idx] = true;
+}
+
+pub fn is_revealed_trap(idx: usize) -> bool {
+    let lock = SPATIAL_MAP.lock();
+    lock.revealed_traps[idx]
+}
+
+///////////////////////////////////////////////////////////////////////////////
+/// Closed Doors
+///////////////////////////////////////////////////////////////////////////////
+
+pub fn clear_closed_doors() {
+    let mut lock = SPATIAL_MAP.lock();
+    lock.closed_doors.iter_mut().for_each(|d| {
+        *d = false;
+    });
+}
+
+/// A closed door that isn't locked, which anything able to open doors can get through
+pub fn set_closed_door(idx: usize) {
+    let mut lock = SPATIAL_MAP.lock();
+    lock.closed_doors[idx] = true;
+}
+
+pub fn is_closed_door(idx: usize) -> bool {
+    let lock = SPATIAL_MAP.lock();
+    lock.closed_doors[idx]
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 /// Tile Content
 ///////////////////////////////////////////////////////////////////////////////
@@ -1,53 +1,50 @@
 use crate::prelude::*;
+use std::collections::HashMap;
 
-impl GameTile {
-    pub fn get_tile_glyph_default(&self, map: &Map, idx: usize) -> (FontCharType, ColorPair) {
-        let (glyph, fg) = match self.tile_type {
-            TileType::Wall => {
-                let pt = map.index_to_point2d(idx);
-                (wall_glyph(map, pt.x, pt.y), RGB::named(GREEN))
-            }
-            _ => (self.glyph, self.color.fg.into()),
-        };
+/// How a single `TileType` is drawn under a theme. Anything left as `None` falls back to the
+/// tile's own glyph or color.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct ThemeTile {
+    pub fg: Option<RGB>,
+    pub wall_auto_tile: bool,
+    /// The theme dresses this tile as something that burns, such as forest undergrowth
+    pub flammable: bool,
+    pub glyph: Option<FontCharType>,
+}
 
-        (glyph, ColorPair::new(fg, BLACK))
-    }
+/// A resolved level theme. It lives on the `Map` itself, so a stored level keeps its look even if
+/// the theme raws change later on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct MapTheme {
+    pub name: String,
+    pub fallback: Option<ThemeTile>,
+    pub tiles: HashMap<TileType, ThemeTile>,
+}
 
-    pub fn get_forest_glyph(&self) -> (FontCharType, ColorPair) {
-        let (glyph, fg) = match self.tile_type {
-            TileType::Wall => (to_cp437('♣'), RGB::from_f32(0.0, 0.6, 0.0)),
-            TileType::Road => (self.glyph, RGB::named(YELLOW)),
-            TileType::Grass => (self.glyph, self.color.fg.into()),
-            TileType::Bridge => (self.glyph, self.color.fg.into()),
-            TileType::Gravel => (self.glyph, self.color.fg.into()),
-            TileType::UpStairs => (self.glyph, self.color.fg.into()),
-            TileType::DeepWater => (self.glyph, self.color.fg.into()),
-            TileType::DownStairs => (self.glyph, self.color.fg.into()),
-            TileType::ShallowWater => (self.glyph, self.color.fg.into()),
-            _ => (to_cp437('"'), RGB::from_f32(0.4, 0.4, 0.4)),
-        };
+impl MapTheme {
+    pub fn tile_glyph(&self, map: &Map, idx: usize) -> (FontCharType, ColorPair) {
+        let tile = &map.tiles[idx];
 
-        (glyph, ColorPair::new(fg, BLACK))
-    }
+        let (glyph, fg) = match self.tiles.get(&tile.tile_type).or(self.fallback.as_ref()) {
+            Some(theme_tile) => {
+                let glyph = if theme_tile.wall_auto_tile {
+                    let pt = map.index_to_point2d(idx);
+                    wall_glyph(map, pt.x, pt.y)
+                } else {
+                    theme_tile.glyph.unwrap_or(tile.glyph)
+                };
 
-    pub fn get_limestone_glyph(&self) -> (FontCharType, ColorPair) {
-        let (glyph, fg) = match self.tile_type {
-            TileType::Wall => (to_cp437('▒'), RGB::named(GRAY61)),
-            TileType::Road => (self.glyph, RGB::named(YELLOW)),
-            TileType::Grass => (self.glyph, self.color.fg.into()),
-            TileType::Bridge => (self.glyph, self.color.fg.into()),
-            TileType::Gravel => (self.glyph, self.color.fg.into()),
-            TileType::UpStairs => (self.glyph, self.color.fg.into()),
-            TileType::DeepWater => (to_cp437('░'), RGB::from_f32(0.2, 0.2, 1.0)),
-            TileType::Stalactite => (self.glyph, RGB::named(GRAY61)),
-            TileType::Stalagmite => (self.glyph, RGB::named(GRAY61)),
-            TileType::DownStairs => (self.glyph, self.color.fg.into()),
-            TileType::ShallowWater => (to_cp437('░'), self.color.fg.into()),
-            _ => (to_cp437('\''), RGB::from_f32(0.0, 0.6, 0.0)),
+                (glyph, theme_tile.fg.unwrap_or_else(|| tile.color.fg.into()))
+            }
+            None => (tile.glyph, tile.color.fg.into()),
         };
 
         (glyph, ColorPair::new(fg, BLACK))
     }
+
+    pub fn is_flammable(&self, tile_type: TileType) -> bool {
+        self.tiles.get(&tile_type).or(self.fallback.as_ref()).map_or(false, |t| t.flammable)
+    }
 }
 
 #[rustfmt::skip]
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+
+/// How long a freshly ignited tile burns for
+pub const FIRE_TURNS: i32 = 6;
+/// Smoke left behind when a fire burns out
+const SMOKE_TURNS: i32 = 4;
+/// Gas and smoke thinner than this no longer drift into neighboring tiles
+const MIN_SPREAD_TURNS: i32 = 3;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TileEffectKind {
+    Fire,
+    PoisonGas,
+    Smoke,
+    Water,
+}
+
+impl TileEffectKind {
+    pub fn blocks_vision(&self) -> bool { matches!(self, TileEffectKind::Smoke) }
+
+    pub fn color(&self) -> RGB {
+        match self {
+            TileEffectKind::Fire => RGB::from_u8(170, 50, 0),
+            TileEffectKind::PoisonGas => RGB::from_u8(40, 110, 20),
+            TileEffectKind::Smoke => RGB::from_u8(80, 80, 80),
+            TileEffectKind::Water => RGB::from_u8(0, 40, 120),
+        }
+    }
+}
+
+/// An environmental effect sitting on a single tile, counting down each turn
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct TileEffect {
+    pub kind: TileEffectKind,
+    pub turns: i32,
+}
+
+impl Map {
+    pub fn is_flammable(&self, idx: usize) -> bool {
+        let tile = &self.tiles[idx];
+        tile.walkable && (tile.flammable || self.theme.is_flammable(tile.tile_type))
+    }
+
+    pub fn tile_effect(&self, idx: usize) -> Option<TileEffectKind> {
+        self.tile_effects.get(&idx).map(|effect| effect.kind)
+    }
+
+    pub fn effect_blocks_vision(&self, idx: usize) -> bool {
+        self.tile_effect(idx).map_or(false, |kind| kind.blocks_vision())
+    }
+
+    /// Places an effect on a tile, returning false if the tile won't take it.
+    /// Fire only catches on flammable ground, and water puts out whatever is burning.
+    pub fn add_tile_effect(&mut self, idx: usize, kind: TileEffectKind, turns: i32) -> bool {
+        if idx >= self.tiles.len() || self.tiles[idx].opaque {
+            return false;
+        }
+
+        let current = self.tile_effects.get(&idx).copied();
+        match kind {
+            TileEffectKind::Fire => {
+                if !self.is_flammable(idx)
+                    || self.tiles[idx].extinguishes_fire
+                    || current.map_or(false, |e| e.kind == TileEffectKind::Water)
+                {
+                    return false;
+                }
+            }
+            // Gas and smoke drift around flames rather than smothering them
+            TileEffectKind::PoisonGas | TileEffectKind::Smoke => {
+                if current.map_or(false, |e| e.kind == TileEffectKind::Fire) {
+                    return false;
+                }
+            }
+            TileEffectKind::Water => {}
+        }
+
+        if let Some(current) = current {
+            if current.kind == kind && current.turns >= turns {
+                return false;
+            }
+        }
+
+        self.tile_effects.insert(idx, TileEffect { kind, turns });
+        true
+    }
+
+    /// Ages every tile effect by a turn and lets fire, gas and smoke spread.
+    /// Returns true if anything changed.
+    pub fn tick_tile_effects(&mut self) -> bool {
+        if self.tile_effects.is_empty() {
+            return false;
+        }
+
+        // Work from a snapshot so effects that spread this turn don't spread again
+        let current = self.tile_effects.iter().map(|(idx, effect)| (*idx, *effect)).collect::<Vec<_>>();
+
+        for (idx, effect) in current {
+            let remaining = effect.turns - 1;
+            if remaining > 0 {
+                self.tile_effects.insert(idx, TileEffect { kind: effect.kind, turns: remaining });
+            } else {
+                self.tile_effects.remove(&idx);
+            }
+
+            match effect.kind {
+                TileEffectKind::Fire => {
+                    for neighbor in self.neighbor_indices(idx) {
+                        if !self.tile_effects.contains_key(&neighbor) && crate::rng::roll_dice(1, 3) == 1 {
+                            self.add_tile_effect(neighbor, TileEffectKind::Fire, FIRE_TURNS);
+                        }
+                    }
+
+                    // Burnt ground can't catch again
+                    if remaining <= 0 {
+                        self.tiles[idx] = GameTile::gravel();
+                        self.add_tile_effect(idx, TileEffectKind::Smoke, SMOKE_TURNS);
+                    }
+                }
+                TileEffectKind::PoisonGas | TileEffectKind::Smoke => {
+                    if effect.turns >= MIN_SPREAD_TURNS {
+                        let open = self
+                            .neighbor_indices(idx)
+                            .into_iter()
+                            .filter(|n| self.tiles[*n].walkable && !self.tile_effects.contains_key(n))
+                            .collect::<Vec<_>>();
+
+                        if !open.is_empty() {
+                            let target = open[crate::rng::range(0, open.len() as i32) as usize];
+                            self.add_tile_effect(target, effect.kind, effect.turns / 2);
+                        }
+                    }
+                }
+                TileEffectKind::Water => {}
+            }
+        }
+
+        true
+    }
+
+    fn neighbor_indices(&self, idx: usize) -> Vec<usize> {
+        let pt = self.index_to_point2d(idx);
+        let mut neighbors = Vec::new();
+        for y in -1..=1 {
+            for x in -1..=1 {
+                let neighbor = pt + Point::new(x, y);
+                if (x != 0 || y != 0) && self.in_bounds(neighbor) {
+                    neighbors.push(self.point2d_to_index(neighbor));
+                }
+            }
+        }
+        neighbors
+    }
+}
@@ -1,4 +1,14 @@
 use crate::prelude::*;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+lazy_static! {
+    /// The tile definitions from the raws. They're copied out when the raws load, so tiles can be made
+    /// without locking `RAWS` and map builders are free to hold that lock while they work.
+    static ref TILE_TABLE: RwLock<HashMap<TileType, GameTile>> = RwLock::new(HashMap::new());
+}
+
+pub fn set_tile_table(tiles: HashMap<TileType, GameTile>) { *TILE_TABLE.write() = tiles; }
 
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize, Debug)]
 pub enum TileType {
@@ -18,11 +28,17 @@ pub enum TileType {
     ShallowWater,
 }
 
+/// Tile properties are defined per `TileType` in `tiles.ron`
 #[derive(Clone, Copy, Serialize, Deserialize, Debug)]
 pub struct GameTile {
     pub cost: f32,
     pub opaque: bool,
     pub walkable: bool,
+    pub swimmable: bool,
+    pub slows: bool,
+    pub extinguishes_fire: bool,
+    pub flammable: bool,
+    pub damage: i32,
     pub color: ColorPair,
     pub tile_type: TileType,
     pub glyph: FontCharType,
@@ -49,22 +65,17 @@ impl std::hash::Hash for GameTile {
     }
 }
 
-impl_new!(
-    GameTile,
-    cost: f32,
-    opaque: bool,
-    walkable: bool,
-    color: ColorPair,
-    tile_type: TileType,
-    glyph: FontCharType
-);
-
 impl Default for GameTile {
     fn default() -> Self {
         Self {
             cost: 1.0,
             opaque: false,
             walkable: true,
+            swimmable: false,
+            slows: false,
+            extinguishes_fire: false,
+            flammable: false,
+            damage: 0,
             tile_type: TileType::Wall,
             glyph: FontCharType::default(),
             color: ColorPair::new((0, 0, 0), (0, 0, 0)),
@@ -73,141 +84,41 @@ impl Default for GameTile {
 }
 
 impl GameTile {
-    pub fn door() -> Self {
-        Self {
-            opaque: true,
-            walkable: false,
-            glyph: to_cp437('+'),
-            tile_type: TileType::Door,
-            color: ColorPair::new(CHOCOLATE, BLACK),
-            ..Default::default()
+    pub fn from_type(tile_type: TileType) -> Self {
+        match TILE_TABLE.read().get(&tile_type) {
+            Some(tile) => *tile,
+            None => {
+                log::warn!("No tile definition in raws for {:?}", tile_type);
+                Self { tile_type, ..Default::default() }
+            }
         }
     }
 
-    pub fn floor() -> Self {
-        Self {
-            glyph: to_cp437('.'),
-            tile_type: TileType::Floor,
-            color: ColorPair::new(DARK_GRAY, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn door() -> Self { Self::from_type(TileType::Door) }
 
-    pub fn wall() -> Self {
-        Self {
-            opaque: true,
-            walkable: false,
-            glyph: to_cp437('#'),
-            tile_type: TileType::Wall,
-            color: ColorPair::new(DARK_GRAY, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn floor() -> Self { Self::from_type(TileType::Floor) }
 
-    pub fn stairs_down() -> Self {
-        Self {
-            glyph: to_cp437('>'),
-            tile_type: TileType::DownStairs,
-            color: ColorPair::new(CYAN, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn wall() -> Self { Self::from_type(TileType::Wall) }
 
-    pub fn stairs_up() -> Self {
-        Self {
-            glyph: to_cp437('<'),
-            tile_type: TileType::UpStairs,
-            color: ColorPair::new(CYAN, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn stairs_down() -> Self { Self::from_type(TileType::DownStairs) }
 
-    pub fn road() -> Self {
-        Self {
-            cost: 0.8,
-            glyph: to_cp437('≡'),
-            tile_type: TileType::Road,
-            color: ColorPair::new(GRAY, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn stairs_up() -> Self { Self::from_type(TileType::UpStairs) }
 
-    pub fn grass() -> Self {
-        Self {
-            cost: 1.1,
-            glyph: to_cp437('"'),
-            tile_type: TileType::Grass,
-            color: ColorPair::new(GREEN, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn road() -> Self { Self::from_type(TileType::Road) }
 
-    pub fn bridge() -> Self {
-        Self {
-            glyph: to_cp437('.'),
-            tile_type: TileType::Bridge,
-            color: ColorPair::new(CHOCOLATE, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn grass() -> Self { Self::from_type(TileType::Grass) }
 
-    pub fn gravel() -> Self {
-        Self {
-            glyph: to_cp437(';'),
-            tile_type: TileType::Gravel,
-            color: ColorPair::new(GRAY44, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn bridge() -> Self { Self::from_type(TileType::Bridge) }
 
-    pub fn wood_floor() -> Self {
-        Self {
-            glyph: to_cp437('░'),
-            tile_type: TileType::WoodFloor,
-            color: ColorPair::new(CHOCOLATE, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn gravel() -> Self { Self::from_type(TileType::Gravel) }
 
-    pub fn stalagmite() -> Self {
-        Self {
-            opaque: true,
-            walkable: false,
-            glyph: to_cp437('╥'),
-            tile_type: TileType::Stalagmite,
-            color: ColorPair::new(GRAY44, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn wood_floor() -> Self { Self::from_type(TileType::WoodFloor) }
 
-    pub fn stalactite() -> Self {
-        Self {
-            opaque: true,
-            walkable: false,
-            glyph: to_cp437('╨'),
-            tile_type: TileType::Stalactite,
-            color: ColorPair::new(GRAY44, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn stalagmite() -> Self { Self::from_type(TileType::Stalagmite) }
 
-    pub fn deep_water() -> Self {
-        Self {
-            walkable: false,
-            glyph: to_cp437('~'),
-            tile_type: TileType::DeepWater,
-            color: ColorPair::new(BLUE, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn stalactite() -> Self { Self::from_type(TileType::Stalactite) }
 
-    pub fn shallow_water() -> Self {
-        Self {
-            cost: 1.2,
-            glyph: to_cp437('~'),
-            tile_type: TileType::ShallowWater,
-            color: ColorPair::new(CYAN, BLACK),
-            ..Default::default()
-        }
-    }
+    pub fn deep_water() -> Self { Self::from_type(TileType::DeepWater) }
+
+    pub fn shallow_water() -> Self { Self::from_type(TileType::ShallowWater) }
 }
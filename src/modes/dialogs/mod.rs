@@ -2,8 +2,10 @@ use super::*;
 
 pub mod app_quit_dialog;
 pub mod message_box;
+pub mod orders_dialog;
 pub mod yes_no_dialog;
 
 pub use app_quit_dialog::*;
 pub use message_box::*;
+pub use orders_dialog::*;
 pub use yes_no_dialog::*;
@@ -0,0 +1,76 @@
+use super::*;
+use crate::GameWorld;
+
+const ORDERS: [(&str, OrdersDialogModeResult); 3] = [
+    ("Follow me", OrdersDialogModeResult::Follow),
+    ("Stay here", OrdersDialogModeResult::Stay),
+    ("Attack a target", OrdersDialogModeResult::Attack),
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum OrdersDialogModeResult {
+    Cancelled,
+    Follow,
+    Stay,
+    Attack,
+}
+
+#[derive(Debug, Default)]
+pub struct OrdersDialogMode {
+    selection: usize,
+}
+
+/// A small menu for giving orders to the whole party.
+impl OrdersDialogMode {
+    pub fn new() -> Self { Self::default() }
+}
+
+impl State for OrdersDialogMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        _state: &mut Self::State,
+        _pop_result: &Option<Self::StateResult>,
+    ) -> StateReturn<Self::State, Self::StateResult> {
+        if let Some(key) = term.key {
+            match key {
+                VirtualKeyCode::Escape => {
+                    return (Transition::Pop(OrdersDialogModeResult::Cancelled.into()), TransitionControl::Update);
+                }
+                VirtualKeyCode::Up => self.selection = (self.selection + ORDERS.len() - 1) % ORDERS.len(),
+                VirtualKeyCode::Down => self.selection = (self.selection + 1) % ORDERS.len(),
+                VirtualKeyCode::Return => {
+                    return (Transition::Pop(ORDERS[self.selection].1.into()), TransitionControl::Update);
+                }
+                _ => {}
+            }
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, _state: &mut Self::State, _active: bool) {
+        let mut draw_batch = DrawBatch::new();
+        draw_batch.target(LAYER_TEXT);
+
+        let width = ORDERS.iter().map(|(label, _)| label.len()).max().unwrap_or(0) as i32 + 6;
+        let box_rect = center_box(
+            &mut draw_batch,
+            (MAP_PANEL_WIDTH, MAP_PANEL_HEIGHT),
+            BoxConfig::new((width, ORDERS.len() as i32 + 3), ColorPair::new(WHITE, BLACK), true, false),
+        );
+
+        let (x, y) = (box_rect.x1, box_rect.y1);
+        draw_batch.print_color(Point::new(x + 2, y + 1), "Orders", ColorPair::new(YELLOW, BLACK));
+
+        for (i, (label, _)) in ORDERS.iter().enumerate() {
+            let bg = if i == self.selection { crate::utils::SELECTED_BG } else { BLACK };
+            draw_batch.print_color(Point::new(x + 2, y + 2 + i as i32), label, ColorPair::new(WHITE, bg));
+        }
+
+        draw_batch.submit(BATCH_UI_INV).expect("Batch error"); // On top of everything
+    }
+}
@@ -1,8 +1,11 @@
 use super::*;
+use crate::game_over_mode::GameOverMode;
 use crate::inventory_mode::InventoryMode;
 
 mod player;
+mod travel;
 pub use player::*;
+use travel::{Travel, TravelGoal, TravelStatus};
 
 ////////////////////////////////////////////////////////////////////////////////
 /// Result
@@ -16,27 +19,33 @@ pub enum DungeonModeResult {
 /// Mode
 ////////////////////////////////////////////////////////////////////////////////
 
-pub struct DungeonMode {}
+pub struct DungeonMode {
+    /// A walk the player is partway through, from clicking the map or auto-exploring
+    travel: Option<Travel>,
+}
 
 impl std::fmt::Debug for DungeonMode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        f.debug_struct("DungeonMode").finish()
+        f.debug_struct("DungeonMode").field("travel", &self.travel).finish()
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 
 impl DungeonMode {
-    pub fn new(app: &mut App) -> Self {
+    pub fn new(app: &mut App, seed: u64) -> Self {
+        // Setup Plugins, once. Later runs reuse the systems of the first.
+        if !app.world.contains_resource::<RunSeed>() {
+            app.add_plugin(SystemsPlugin);
+            app.add_plugin(spawner::SpawnerPlugin);
+        }
+
         // Setup State
+        app.insert_resource(RunSeed(seed));
         app.insert_resource(TurnState::AwaitingInput);
         app.insert_resource(NextState(AppState::NewGame));
 
-        // Setup Plugins
-        app.add_plugin(SystemsPlugin);
-        app.add_plugin(spawner::SpawnerPlugin);
-
-        Self {}
+        Self { travel: None }
     }
 }
 
@@ -53,8 +62,16 @@ impl State for DungeonMode {
         // Update Systems
         state.app.update();
 
+        if state.app.world.resource::<CurrentState<AppState>>().0 == AppState::GameOver {
+            return (
+                Transition::Switch(GameOverMode::new(&mut state.app.world).boxed()),
+                TransitionControl::Immediate,
+            );
+        }
+
         if let Some(result) = pop_result {
             let world = &mut state.app.world;
+            self.travel = None;
 
             match result {
                 // App Quit
@@ -97,6 +114,33 @@ impl State for DungeonMode {
                         self.end_turn(world);
                     }
                 },
+                // Spellbook
+                ModeResult::SpellbookModeResult(result) => match result {
+                    SpellbookModeResult::Cancelled => {}
+                    SpellbookModeResult::CastSpell(spell, target) => {
+                        self.cast_spell(world, spell, *target);
+                        self.end_turn(world);
+                    }
+                },
+                ModeResult::MessageLogModeResult(_) => {}
+                ModeResult::LookModeResult(_) => {}
+                // Party orders don't take a turn
+                ModeResult::OrdersDialogModeResult(result) => match result {
+                    OrdersDialogModeResult::Cancelled => {}
+                    OrdersDialogModeResult::Follow => self.give_orders(world, CompanionOrder::Follow),
+                    OrdersDialogModeResult::Stay => self.give_orders(world, CompanionOrder::Stay),
+                    OrdersDialogModeResult::Attack => {
+                        if let Some(targeting) = self.attack_targeting(term, world) {
+                            return (Transition::Push(targeting.boxed()), TransitionControl::Update);
+                        }
+                    }
+                },
+                // The party goes after whatever the player picked out
+                ModeResult::TargetingModeResult(result) => match result {
+                    TargetingModeResult::Cancelled => {}
+                    TargetingModeResult::Target(_, pt) => self.order_attack(world, *pt),
+                },
+
                 _ => unreachable!("Unknown popped dungeon result: [{:?}]", result),
             };
         }
@@ -104,10 +148,37 @@ impl State for DungeonMode {
         let turn_state = *state.app.world.resource::<TurnState>();
         match turn_state {
             TurnState::MagicMapReveal(row) => self.reveal_map(&mut state.app.world, row),
+            TurnState::AwaitingInput if self.travel.is_some() => {
+                self.update_travel(term, &mut state.app.world)
+            }
             TurnState::AwaitingInput => match player_input(term, &mut state.app.world) {
                 PlayerInputResult::NoResult => {}
                 PlayerInputResult::AppQuit => return self.app_quit_dialog(),
                 PlayerInputResult::TurnDone => self.end_turn(&mut state.app.world),
+                PlayerInputResult::TravelTo(target) => {
+                    self.travel = Travel::new(&mut state.app.world, TravelGoal::Point(target))
+                }
+                PlayerInputResult::AutoExplore => {
+                    self.travel = Travel::new(&mut state.app.world, TravelGoal::Explore)
+                }
+                PlayerInputResult::ShowOrders => {
+                    return (Transition::Push(OrdersDialogMode::new().boxed()), TransitionControl::Update)
+                }
+                PlayerInputResult::ShowSpellbook => {
+                    return (
+                        Transition::Push(SpellbookMode::new(&mut state.app.world).boxed()),
+                        TransitionControl::Update,
+                    )
+                }
+                PlayerInputResult::ShowMessageLog => {
+                    return (Transition::Push(MessageLogMode::new().boxed()), TransitionControl::Update)
+                }
+                PlayerInputResult::ShowLook => {
+                    return (
+                        Transition::Push(LookMode::new(&mut state.app.world).boxed()),
+                        TransitionControl::Update,
+                    )
+                }
                 PlayerInputResult::ShowInventory => {
                     return (
                         Transition::Push(InventoryMode::new(&mut state.app.world).boxed()),
@@ -147,15 +218,42 @@ impl DungeonMode {
 
     fn end_turn(&self, world: &mut World) {
         bo_logging::record_event(TURN_DONE_EVENT, 1);
+        let depth = world.resource::<Map>().depth;
+        world.resource_mut::<RunStats>().record_turn(depth);
+
         let mut runwriter = world.resource_mut::<TurnState>();
         *runwriter = TurnState::PlayerTurn
     }
 
+    /// Takes the next step of a walk. Any key or click calls it off.
+    fn update_travel(&mut self, term: &BTerm, world: &mut World) {
+        if term.key.is_some() || term.left_click {
+            self.travel = None;
+            return;
+        }
+
+        let status = match self.travel.as_mut() {
+            Some(travel) => travel.update(world, term.frame_time_ms),
+            None => return,
+        };
+
+        match status {
+            TravelStatus::Waiting => {}
+            TravelStatus::Stepped => self.end_turn(world),
+            TravelStatus::Finished => self.travel = None,
+        }
+    }
+
     fn use_item(&self, world: &mut World, item: &Entity, pt: Option<Point>) {
         let p = *world.resource::<Entity>();
         world.send_event(WantsToUseItem(p, *item, pt));
     }
 
+    fn cast_spell(&self, world: &mut World, spell: &Entity, pt: Option<Point>) {
+        let p = *world.resource::<Entity>();
+        world.send_event(WantsToCastSpell(p, *spell, pt));
+    }
+
     fn drop_item(&self, world: &mut World, item: &Entity) {
         let p = *world.resource::<Entity>();
         world.send_event(WantsToDropItem(p, *item));
@@ -171,6 +269,47 @@ impl DungeonMode {
         world.send_event(WantsToRemoveItem(p, *equipment));
     }
 
+    fn attack_targeting(&self, term: &mut BTerm, world: &mut World) -> Option<TargetingMode> {
+        let mut companion_q = world.query_filtered::<Entity, (With<Companion>, With<Point>)>();
+        let companion = match companion_q.iter(world).next() {
+            Some(companion) => companion,
+            None => {
+                bo_logging::Logger::new().append("You have nobody to give orders to.").log();
+                return None;
+            }
+        };
+
+        let player = *world.resource::<Entity>();
+        let range = world.get::<FieldOfView>(player).map_or(0, |fov| fov.radius);
+        Some(TargetingMode::new(term, world, companion, range, false))
+    }
+
+    fn order_attack(&self, world: &mut World, pt: Point) {
+        let player = *world.resource::<Entity>();
+        let in_sight = world.get::<FieldOfView>(player).map_or(false, |fov| fov.visible_tiles.contains(&pt));
+
+        let target = crate::spatial::get_tile_content_clone_pt(pt)
+            .into_iter()
+            .find(|entity| world.get::<Monster>(*entity).is_some());
+
+        match target {
+            Some(target) if in_sight => self.give_orders(world, CompanionOrder::Attack(target)),
+            _ => bo_logging::Logger::new().append("There is no enemy there.").log(),
+        }
+    }
+
+    fn give_orders(&self, world: &mut World, order: CompanionOrder) {
+        let mut companion_q = world.query_filtered::<&mut Companion, With<Point>>();
+        companion_q.iter_mut(world).for_each(|mut companion| companion.order = order);
+
+        let message = match order {
+            CompanionOrder::Follow => "You tell your party to follow you.",
+            CompanionOrder::Stay => "You tell your party to stay here.",
+            CompanionOrder::Attack(_) => "You point your party at the enemy.",
+        };
+        bo_logging::Logger::new().append(message).log();
+    }
+
     fn reveal_map(&self, world: &mut World, row: i32) {
         let height: i32;
         {
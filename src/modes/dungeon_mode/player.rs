@@ -10,10 +10,25 @@ pub enum PlayerInputResult {
     ShowDrop,
     ShowRemove,
     ShowInventory,
+    ShowOrders,
+    ShowSpellbook,
+    ShowMessageLog,
+    ShowLook,
+    TravelTo(Point),
+    AutoExplore,
     _ShowInventoryShortcut,
 }
 
 pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
+    // Clicking the map walks there
+    if ctx.left_click {
+        let camera = world.resource::<CameraView>();
+        let target = camera.screen_to_world(ctx.mouse_point());
+        if camera.viewport.point_in_rect(target) {
+            return PlayerInputResult::TravelTo(target);
+        }
+    }
+
     match ctx.get_key() {
         None => return PlayerInputResult::NoResult, // Nothing happened
         Some(key) => {
@@ -37,6 +52,26 @@ pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
                 GameKey::Remove => return PlayerInputResult::ShowRemove,
                 GameKey::Drop => return PlayerInputResult::ShowDrop,
                 GameKey::SkipTurn => return PlayerInputResult::TurnDone,
+                GameKey::Orders => {
+                    if world.query::<&Companion>().iter(world).next().is_some() {
+                        return PlayerInputResult::ShowOrders;
+                    }
+                    bo_logging::Logger::new().append("You have no companions to give orders to.").log();
+                    return PlayerInputResult::NoResult;
+                }
+
+                GameKey::Spellbook => {
+                    let player = *world.resource::<Entity>();
+                    if world.get::<KnownSpells>(player).map_or(false, |known| !known.spells.is_empty()) {
+                        return PlayerInputResult::ShowSpellbook;
+                    }
+                    bo_logging::Logger::new().append("You don't know any spells.").log();
+                    return PlayerInputResult::NoResult;
+                }
+
+                GameKey::MessageLog => return PlayerInputResult::ShowMessageLog,
+                GameKey::Look => return PlayerInputResult::ShowLook,
+                GameKey::AutoExplore => return PlayerInputResult::AutoExplore,
 
                 GameKey::TakeStairs => {
                     if try_next_level(world) {
@@ -47,6 +82,11 @@ pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
                 }
 
                 GameKey::Pickup => try_pickup_item(world, player_query),
+                GameKey::Search => try_search(world, player_query),
+                GameKey::Disarm => try_disarm_trap(world, player_query),
+                GameKey::Butcher => try_butcher(world, player_query),
+                GameKey::CloseDoor => try_close_door(world, player_query),
+                GameKey::LockDoor => try_lock_door(world, player_query),
 
                 _ => {}
             }
@@ -56,6 +96,12 @@ pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
     PlayerInputResult::TurnDone
 }
 
+/// Steps the player one tile, bumping into whatever is there just as a movement key would
+pub fn move_player(world: &mut World, delta: Point) {
+    let player_query = world.query_filtered::<(Entity, &Point), (With<Player>, Without<Monster>)>();
+    try_move_player(world, delta, player_query);
+}
+
 fn try_move_player(
     world: &mut World,
     delta: Point,
@@ -76,8 +122,27 @@ fn try_move_player(
             }
         }
 
+        // Walking into a companion or townsperson trades places with it,
+        // and into a recruitable NPC asks it to join
+        let mut party_query = world.query_filtered::<
+            (Entity, &Point, Option<&Recruitable>),
+            Or<(With<Companion>, With<Recruitable>, With<Bystander>)>,
+        >();
+        let mut party_member = None;
+        for (entity, pos, recruitable) in party_query.iter(world) {
+            if *pos == destination {
+                party_member = Some((entity, recruitable.is_some()));
+            }
+        }
+
         if let Some(entity) = hit_something {
             world.send_event(WantsToAttack(player, entity))
+        } else if let Some((entity, recruitable)) = party_member {
+            if recruitable {
+                world.send_event(WantsToRecruit(player, entity))
+            } else {
+                world.send_event(WantsToSwapPlaces(player, entity))
+            }
         } else {
             world.send_event(WantsToMove(player, destination))
         }
@@ -108,3 +173,75 @@ fn try_pickup_item(
 
     bo_logging::Logger::new().append("There is nothing here to pick up.").log()
 }
+
+fn try_search(
+    world: &mut World,
+    mut player_query: QueryState<(Entity, &Point), (With<Player>, Without<Monster>)>,
+) {
+    let (player, _) = player_query.single(world);
+    world.send_event(WantsToSearch(player));
+}
+
+fn try_disarm_trap(
+    world: &mut World,
+    mut player_query: QueryState<(Entity, &Point), (With<Player>, Without<Monster>)>,
+) {
+    let mut traps_query = world.query_filtered::<(Entity, &Point), (With<EntryTrigger>, Without<Hidden>)>();
+    let (player, pos) = player_query.single(world);
+
+    for (entity, trap_pos) in traps_query.iter(world) {
+        if DistanceAlg::Pythagoras.distance2d(*pos, *trap_pos) < 1.5 {
+            return world.send_event(WantsToDisarm(player, entity));
+        }
+    }
+
+    bo_logging::Logger::new().append("There is no trap nearby to disarm.").log()
+}
+
+fn try_butcher(
+    world: &mut World,
+    mut player_query: QueryState<(Entity, &Point), (With<Player>, Without<Monster>)>,
+) {
+    let mut corpse_query = world.query_filtered::<(Entity, &Point), With<Corpse>>();
+    let (player, pos) = player_query.single(world);
+
+    for (entity, corpse_pos) in corpse_query.iter(world) {
+        if *corpse_pos == *pos {
+            return world.send_event(WantsToButcher(player, entity));
+        }
+    }
+
+    bo_logging::Logger::new().append("There is nothing here to butcher.").log()
+}
+
+fn try_close_door(
+    world: &mut World,
+    mut player_query: QueryState<(Entity, &Point), (With<Player>, Without<Monster>)>,
+) {
+    let mut doors_query = world.query::<(Entity, &Point, &Door)>();
+    let (player, pos) = player_query.single(world);
+
+    for (entity, door_pos, door) in doors_query.iter(world) {
+        if door.0 && DistanceAlg::Pythagoras.distance2d(*pos, *door_pos) < 1.5 {
+            return world.send_event(WantsToCloseDoor(player, entity));
+        }
+    }
+
+    bo_logging::Logger::new().append("There is no open door nearby.").log()
+}
+
+fn try_lock_door(
+    world: &mut World,
+    mut player_query: QueryState<(Entity, &Point), (With<Player>, Without<Monster>)>,
+) {
+    let mut doors_query = world.query_filtered::<(Entity, &Point), (With<Door>, With<Lock>)>();
+    let (player, pos) = player_query.single(world);
+
+    for (entity, door_pos) in doors_query.iter(world) {
+        if DistanceAlg::Pythagoras.distance2d(*pos, *door_pos) < 1.5 {
+            return world.send_event(WantsToLockDoor(player, entity));
+        }
+    }
+
+    bo_logging::Logger::new().append("There is no lock nearby.").log()
+}
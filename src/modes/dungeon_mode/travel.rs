@@ -0,0 +1,208 @@
+use super::*;
+use std::collections::HashSet;
+
+/// Time between steps, so the walk can be followed on screen
+const TRAVEL_STEP_MS: f32 = 40.0;
+/// How far auto-explore looks for somewhere new to go
+const EXPLORE_MAX_DEPTH: f32 = 2000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelGoal {
+    Point(Point),
+    Explore,
+}
+
+pub enum TravelStatus {
+    /// Not time for the next step yet
+    Waiting,
+    /// The player was sent one step, and the turn should end
+    Stepped,
+    Finished,
+}
+
+/// Walks the player somewhere over several turns, one step per turn, until it gets there
+/// or something worth stopping for happens.
+#[derive(Debug)]
+pub struct Travel {
+    goal: TravelGoal,
+    timer: f32,
+    last_hp: i32,
+    /// Where the player stood before the last step and where it was headed, to tell whether it went anywhere
+    last_step: Option<(Point, Point)>,
+    /// Hostiles already in view when the walk started, which don't interrupt it
+    seen_hostiles: HashSet<Entity>,
+}
+
+impl Travel {
+    pub fn new(world: &mut World, goal: TravelGoal) -> Option<Self> {
+        if let TravelGoal::Point(target) = goal {
+            let map = world.resource::<Map>();
+            let walkable = map.in_bounds(target) && map.tiles[map.point2d_to_index(target)].walkable;
+            if !walkable || !map.revealed.get_bit(target) || target == *world.resource::<Point>() {
+                return None;
+            }
+        }
+
+        Some(Self {
+            goal,
+            timer: TRAVEL_STEP_MS,
+            last_hp: player_hp(world),
+            last_step: None,
+            seen_hostiles: visible_hostiles(world).into_iter().map(|(entity, _)| entity).collect(),
+        })
+    }
+
+    pub fn update(&mut self, world: &mut World, frame_time_ms: f32) -> TravelStatus {
+        self.timer += frame_time_ms;
+        if self.timer < TRAVEL_STEP_MS {
+            return TravelStatus::Waiting;
+        }
+        self.timer = 0.0;
+
+        if let Some(reason) = self.stop_reason(world) {
+            reason.log();
+            return TravelStatus::Finished;
+        }
+
+        let player_pos = *world.resource::<Point>();
+        let next_step = match self.goal {
+            TravelGoal::Point(target) if target == player_pos => return TravelStatus::Finished,
+            TravelGoal::Point(target) => path_step(world.resource::<Map>(), player_pos, target),
+            TravelGoal::Explore => explore_step(world.resource::<Map>(), player_pos),
+        };
+
+        match next_step {
+            Some(next_step) => {
+                self.last_step = Some((player_pos, next_step));
+                move_player(world, next_step - player_pos);
+                TravelStatus::Stepped
+            }
+            None => {
+                let reason = match self.goal {
+                    TravelGoal::Point(_) => "You can't find a way there.",
+                    TravelGoal::Explore => "There's nowhere left to explore.",
+                };
+                bo_logging::Logger::new().append(reason).log();
+                TravelStatus::Finished
+            }
+        }
+    }
+
+    /// Anything that should interrupt the walk since the last step
+    fn stop_reason(&mut self, world: &mut World) -> Option<bo_logging::Logger> {
+        for (entity, name) in visible_hostiles(world) {
+            if !self.seen_hostiles.contains(&entity) {
+                let logger = bo_logging::Logger::new().append("You stop. The").npc_name(&name);
+                return Some(logger.append("comes into view."));
+            }
+        }
+
+        let hp = player_hp(world);
+        if hp < self.last_hp {
+            return Some(bo_logging::Logger::new().append("You stop. You've been hurt!"));
+        }
+        self.last_hp = hp;
+
+        let player_pos = *world.resource::<Point>();
+        if let Some((last_pos, last_target)) = self.last_step {
+            if last_pos == player_pos {
+                // Walking into a closed door opens it, which uses up the step
+                let mut door_q = world.query::<(&Point, &Door)>();
+                if door_q.iter(world).any(|(pos, door)| *pos == last_target && door.0) {
+                    return None;
+                }
+                return Some(bo_logging::Logger::new().append("You stop. Something is in the way."));
+            }
+
+            let mut item_q = world.query_filtered::<(&Point, &Naming), With<Item>>();
+            if let Some((_, name)) = item_q.iter(world).find(|(pos, _)| **pos == player_pos) {
+                let logger = bo_logging::Logger::new().append("You stop. The").item_name(&name.0);
+                return Some(logger.append("is lying here."));
+            }
+        }
+
+        None
+    }
+}
+
+fn player_hp(world: &World) -> i32 {
+    world.get::<CombatStats>(*world.resource::<Entity>()).map_or(0, |stats| stats.hp)
+}
+
+fn visible_hostiles(world: &mut World) -> Vec<(Entity, String)> {
+    let mut hostile_q = world.query_filtered::<(Entity, &Point, &Naming), (With<Monster>, Without<Hidden>)>();
+    let map = world.resource::<Map>();
+    hostile_q
+        .iter(world)
+        .filter(|(_, pos, _)| map.visible.get_bit(**pos))
+        .map(|(entity, _, name)| (entity, name.0.clone()))
+        .collect()
+}
+
+/// The next tile on the way to `target`, going only through tiles the player has seen
+fn path_step(map: &Map, from: Point, target: Point) -> Option<Point> {
+    let path = a_star_search(map.point2d_to_index(from), map.point2d_to_index(target), &KnownMap::new(map));
+    if path.success && path.steps.len() > 1 {
+        Some(map.index_to_point2d(path.steps[1]))
+    } else {
+        None
+    }
+}
+
+/// The next tile towards the nearest seen tile that borders unseen ones. Frontier tiles that can't be
+/// entered leave dips in the map that lead nowhere, so it only ever steps downhill.
+fn explore_step(map: &Map, from: Point) -> Option<Point> {
+    let from_idx = map.point2d_to_index(from);
+    let frontier = (0..map.tiles.len())
+        .filter(|idx| *idx != from_idx && is_frontier(map, map.index_to_point2d(*idx)))
+        .collect::<Vec<_>>();
+    if frontier.is_empty() {
+        return None;
+    }
+
+    let known = KnownMap::new(map);
+    let dijkstra_map =
+        DijkstraMap::new(map.width as usize, map.height as usize, &frontier, &known, EXPLORE_MAX_DEPTH);
+    let here = dijkstra_map.map[from_idx];
+    DijkstraMap::find_lowest_exit(&dijkstra_map, from_idx, &known)
+        .filter(|idx| dijkstra_map.map[*idx] < here)
+        .map(|idx| map.index_to_point2d(idx))
+}
+
+fn is_frontier(map: &Map, pt: Point) -> bool {
+    let idx = map.point2d_to_index(pt);
+    let enterable = map.can_enter_tile(pt) || crate::spatial::is_closed_door(idx) || map.can_swim_into(pt);
+    if !map.revealed.get_bit(pt) || !enterable {
+        return false;
+    }
+
+    (-1..=1).any(|dy| {
+        (-1..=1).any(|dx| {
+            let neighbour = pt + Point::new(dx, dy);
+            map.in_bounds(neighbour) && !map.revealed.get_bit(neighbour)
+        })
+    })
+}
+
+/// The map as the player knows it. Paths never cut through places they haven't seen, but go
+/// wherever the player can, through closed doors that open when walked into and across deep water.
+struct KnownMap<'a>(PathingMap<'a>);
+
+impl<'a> KnownMap<'a> {
+    fn new(map: &'a Map) -> Self { Self(PathingMap::new(map, MoveAbilities::new(true, true))) }
+}
+
+impl BaseMap for KnownMap<'_> {
+    fn is_opaque(&self, idx: usize) -> bool { self.0.is_opaque(idx) }
+
+    fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+        let map = self.0.map;
+        let mut exits = self.0.get_available_exits(idx);
+        exits.retain(|(exit, _)| map.revealed.get_bit(map.index_to_point2d(*exit)));
+        exits
+    }
+
+    fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+        self.0.get_pathing_distance(idx1, idx2)
+    }
+}
@@ -2,6 +2,9 @@ use crate::main_menu_mode::MainMenuMode;
 
 use super::{ModeResult, Transition, *};
 
+mod morgue;
+pub use morgue::*;
+
 pub const MAIN_MENU_SCREEN_WIDTH: usize = 80;
 pub const MAIN_MENU_SCREEN_HEIGHT: usize = 31;
 
@@ -13,19 +16,77 @@ pub enum GameOverModeResult {
 #[derive(Debug)]
 pub enum MenuAction {
     Exit,
+    PlaySeedAgain,
+}
+
+impl MenuAction {
+    fn label(&self) -> &'static str {
+        match self {
+            MenuAction::Exit => "Return to the menu",
+            MenuAction::PlaySeedAgain => "Play this seed again",
+        }
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct GameOverMode {
     selection: usize,
     actions: Vec<MenuAction>,
+    cause_of_death: String,
+    deepest_depth: i32,
+    kills: i32,
+    score: i32,
+    seed: u64,
+    rank: Option<usize>,
+    morgue_file: Option<String>,
 }
 
 /// Show the title screen of the game with a menu that leads into the game proper.
 impl GameOverMode {
-    pub fn new() -> Self {
-        let actions = vec![MenuAction::Exit];
-        Self { actions, selection: 0 }
+    /// Sums up the run and writes the morgue file while the dead player's world is still around
+    pub fn new(world: &mut World) -> Self {
+        let actions = vec![MenuAction::Exit, MenuAction::PlaySeedAgain];
+
+        let morgue_file = write_morgue(world).unwrap_or_else(|e| {
+            log::warn!("write_morgue: {}", e);
+            None
+        });
+
+        let record = Self::run_record(world);
+        let (score, seed) = (record.score, record.seed);
+        let rank = record_run(record).map_err(|e| log::warn!("record_run: {}", e)).ok();
+
+        let run_stats = world.resource::<RunStats>();
+        Self {
+            actions,
+            selection: 0,
+            cause_of_death: run_stats.cause_of_death().to_string(),
+            deepest_depth: run_stats.deepest_depth,
+            kills: run_stats.total_kills(),
+            score,
+            seed,
+            rank,
+            morgue_file,
+        }
+    }
+
+    fn run_record(world: &World) -> RunRecord {
+        let player = *world.resource::<Entity>();
+        let run_stats = world.resource::<RunStats>();
+        let gold = world.get::<Gold>(player).map_or(0, |gold| gold.0);
+        let kills = run_stats.total_kills();
+
+        RunRecord {
+            name: world.get::<Naming>(player).map_or_else(|| "Unknown".to_string(), |n| n.0.clone()),
+            seed: world.resource::<RunSeed>().0,
+            score: score_run(run_stats.deepest_depth, kills, gold),
+            depth: run_stats.deepest_depth,
+            turns: bo_logging::get_event_count(TURN_DONE_EVENT),
+            kills,
+            gold,
+            cause_of_death: run_stats.cause_of_death().to_string(),
+            timestamp: run_timestamp(),
+        }
     }
 
     // pub fn tick(
@@ -134,14 +195,22 @@ impl State for GameOverMode {
                         TransitionControl::Immediate,
                     )
                 }
+                VirtualKeyCode::Up => {
+                    self.selection = (self.selection + self.actions.len() - 1) % self.actions.len()
+                }
+                VirtualKeyCode::Down => self.selection = (self.selection + 1) % self.actions.len(),
                 VirtualKeyCode::Return => {
                     assert!(self.selection < self.actions.len());
 
                     if let Err(e) = self.game_over_cleanup(&mut state.app.world) {
-                        eprintln!("Warning: game_over_cleanup error: {}", e);
+                        log::warn!("game_over_cleanup error: {}", e);
                     }
 
-                    return (Transition::Switch(MainMenuMode::new().boxed()), TransitionControl::Immediate);
+                    let next = match self.actions[self.selection] {
+                        MenuAction::Exit => MainMenuMode::new().boxed(),
+                        MenuAction::PlaySeedAgain => DungeonMode::new(&mut state.app, self.seed).boxed(),
+                    };
+                    return (Transition::Switch(next), TransitionControl::Immediate);
                 }
                 _ => {}
             }
@@ -158,14 +227,19 @@ impl State for GameOverMode {
         sprite.add_to_batch(&mut draw_batch, Point::new(SCREEN_WIDTH / 2 - 15, SCREEN_HEIGHT / 2 - 15));
 
         draw_batch.print_color_centered(15, "Your journey has ended!", ColorPair::new(YELLOW, BLACK));
+        let score = match self.rank {
+            Some(rank) => format!("Score: {} (#{} in the Hall of Fame)", self.score, rank),
+            None => format!("Score: {}", self.score),
+        };
+        draw_batch.print_color_centered(16, &score, ColorPair::new(GOLD, BLACK));
         draw_batch.print_color_centered(
             17,
-            "One day, we'll tell you all about how you did.",
+            &format!("Cause of death: {}.", self.cause_of_death),
             ColorPair::new(WHITE, BLACK),
         );
         draw_batch.print_color_centered(
             18,
-            "That day, sadly, is not in this chapter..",
+            &format!("You reached depth {} and slew {} monsters.", self.deepest_depth, self.kills),
             ColorPair::new(WHITE, BLACK),
         );
 
@@ -185,11 +259,18 @@ impl State for GameOverMode {
             ColorPair::new(RED, BLACK),
         );
 
-        draw_batch.print_color_centered(
-            23,
-            "Press any key to return to the menu.",
-            ColorPair::new(MAGENTA, BLACK),
-        );
+        if let Some(morgue_file) = &self.morgue_file {
+            draw_batch.print_color_centered(
+                22,
+                &format!("Your story was written to {}.", morgue_file),
+                ColorPair::new(GRAY, BLACK),
+            );
+        }
+
+        for (i, action) in self.actions.iter().enumerate() {
+            let color = if i == self.selection { MAGENTA } else { GRAY };
+            draw_batch.print_color_centered(24 + i as i32, action.label(), ColorPair::new(color, BLACK));
+        }
 
         draw_batch.submit(BATCH_ZERO).expect("Error batching title");
     }
@@ -0,0 +1,159 @@
+use crate::prelude::*;
+use std::fmt::Write;
+
+/// How much of the message log makes it into the morgue file
+const MORGUE_LOG_LINES: usize = 50;
+
+/// Writes a plain text account of the run to the working directory, returning the file name if there is one
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_morgue(world: &mut World) -> Result<Option<String>, BoxedError> {
+    let filename = format!("morgue-{}.txt", run_timestamp());
+    std::fs::write(&filename, morgue_text(world)?)?;
+    Ok(Some(filename))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn write_morgue(_world: &mut World) -> Result<Option<String>, BoxedError> { Ok(None) }
+
+pub fn morgue_text(world: &mut World) -> Result<String, BoxedError> {
+    let mut text = String::new();
+    write_character(&mut text, world)?;
+    write_belongings(&mut text, world)?;
+    write_run_stats(&mut text, world)?;
+    write_log(&mut text)?;
+    write_map(&mut text, world)?;
+    Ok(text)
+}
+
+fn write_character(text: &mut String, world: &mut World) -> Result<(), BoxedError> {
+    let player = *world.resource::<Entity>();
+    let name = world.get::<Naming>(player).map_or("Unknown", |n| n.0.as_str()).to_string();
+    let stats = gui::gather_player_stats(world).ok_or("player has no stats")?;
+    let run_stats = world.resource::<RunStats>();
+
+    writeln!(text, "Rusty Roguelike morgue file")?;
+    writeln!(text, "===========================")?;
+    writeln!(text)?;
+    writeln!(
+        text,
+        "{}, a level {} adventurer, died on depth {} after {} turns.",
+        name, stats.level, stats.depth, stats.turns
+    )?;
+    writeln!(text, "Cause of death: {}", run_stats.cause_of_death())?;
+    writeln!(text)?;
+    writeln!(text, "Health: {}/{}    Mana: {}/{}", stats.hp, stats.max_hp, stats.mana, stats.max_mana)?;
+    writeln!(text, "Power:  {:<6}   Armor: {}", stats.power, stats.armor)?;
+    writeln!(text, "XP:     {}/{}    Gold: {}", stats.xp, stats.xp_to_next_level, stats.gold)?;
+    if !stats.statuses.is_empty() {
+        let statuses = stats.statuses.iter().map(|(status, _)| *status).collect::<Vec<_>>();
+        writeln!(text, "Status: {}", statuses.join(", "))?;
+    }
+    writeln!(text)?;
+
+    Ok(())
+}
+
+fn write_belongings(text: &mut String, world: &mut World) -> Result<(), BoxedError> {
+    let player = *world.resource::<Entity>();
+
+    writeln!(text, "-- Equipment --")?;
+    let mut equipped_q = world.query::<(&Naming, &Equipped)>();
+    for (name, equipped) in equipped_q.iter(world).filter(|(_, e)| e.owner == player) {
+        writeln!(text, "  {:?}: {}", equipped.slot, name.0)?;
+    }
+    writeln!(text)?;
+
+    writeln!(text, "-- Inventory --")?;
+    let mut backpack_q = world.query::<(&Naming, &InBackpack)>();
+    let mut items = backpack_q
+        .iter(world)
+        .filter(|(_, b)| b.owner == player)
+        .map(|(n, _)| n.0.clone())
+        .collect::<Vec<_>>();
+    items.sort();
+    if items.is_empty() {
+        writeln!(text, "  (nothing)")?;
+    }
+    for item in items {
+        writeln!(text, "  {}", item)?;
+    }
+    writeln!(text)?;
+
+    if let Some(known) = world.get::<KnownSpells>(player) {
+        writeln!(text, "-- Spells --")?;
+        for spell in known.spells.iter() {
+            writeln!(text, "  {} ({} mana)", spell.name, spell.mana_cost)?;
+        }
+        writeln!(text)?;
+    }
+
+    Ok(())
+}
+
+fn write_run_stats(text: &mut String, world: &mut World) -> Result<(), BoxedError> {
+    let run_stats = world.resource::<RunStats>();
+
+    writeln!(text, "-- Statistics --")?;
+    writeln!(text, "  Deepest depth:    {}", run_stats.deepest_depth)?;
+    writeln!(text, "  Damage inflicted: {}", bo_logging::get_event_count(DAMAGE_INFLICT_EVENT))?;
+    writeln!(text, "  Damage taken:     {}", bo_logging::get_event_count(DAMAGE_TAKE_EVENT))?;
+    writeln!(text, "  Monsters killed:  {}", run_stats.total_kills())?;
+    for (name, count) in run_stats.kills.iter() {
+        writeln!(text, "    {:>3} x {}", count, name)?;
+    }
+    writeln!(text, "  Items used:")?;
+    for (name, count) in run_stats.items_used.iter() {
+        writeln!(text, "    {:>3} x {}", count, name)?;
+    }
+    writeln!(text, "  Turns per depth:")?;
+    for (depth, turns) in run_stats.turns_per_depth.iter() {
+        writeln!(text, "    Depth {:>2}: {}", depth, turns)?;
+    }
+    writeln!(text)?;
+
+    Ok(())
+}
+
+fn write_log(text: &mut String) -> Result<(), BoxedError> {
+    writeln!(text, "-- Last messages --")?;
+
+    let log = bo_logging::clone_log();
+    for entry in log.iter().skip(log.len().saturating_sub(MORGUE_LOG_LINES)) {
+        writeln!(text, "  [{:>5}] {}", entry.turn, entry.display_text())?;
+    }
+    writeln!(text)?;
+
+    Ok(())
+}
+
+/// Everything the player had seen of the level they died on, with whatever was in sight at the end
+fn write_map(text: &mut String, world: &mut World) -> Result<(), BoxedError> {
+    let mut entities = world
+        .query_filtered::<(&Point, &Glyph), (Without<Hidden>, Without<ParticleLifetime>)>()
+        .iter(world)
+        .map(|(pt, glyph)| (*pt, *glyph))
+        .collect::<Vec<_>>();
+
+    // Draw the things that sit underneath first, so actors end up on top of items
+    entities.sort_by(|(_, a), (_, b)| b.render_order.cmp(&a.render_order));
+
+    let map = world.resource::<Map>();
+    let mut rows = vec![vec![' '; map.width as usize]; map.height as usize];
+    for idx in 0..map.tiles.len() {
+        let pt = map.index_to_point2d(idx);
+        if map.revealed.get_bit(pt) {
+            let (glyph, _) = map.theme.tile_glyph(map, idx);
+            rows[pt.y as usize][pt.x as usize] = to_char(glyph as u8);
+        }
+    }
+    for (pt, glyph) in entities.iter().filter(|(pt, _)| map.in_bounds(*pt) && map.visible.get_bit(*pt)) {
+        rows[pt.y as usize][pt.x as usize] = to_char(glyph.glyph as u8);
+    }
+
+    writeln!(text, "-- {} (depth {}) --", map.name, map.depth)?;
+    for row in rows {
+        writeln!(text, "{}", row.into_iter().collect::<String>().trim_end())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+/// How many runs fit on the screen at once
+const HALL_OF_FAME_ROWS: usize = 20;
+const HALL_OF_FAME_WIDTH: i32 = 100;
+
+#[derive(Debug)]
+pub enum HallOfFameModeResult {
+    Cancelled,
+    PlaySeed(u64),
+}
+
+#[derive(Debug, Default)]
+pub struct HallOfFameMode {
+    selection: usize,
+    runs: Vec<RunRecord>,
+}
+
+/// The best runs from the history file. Any of them can be played again from the same seed.
+impl HallOfFameMode {
+    pub fn new() -> Self {
+        let mut runs = high_scores();
+        runs.truncate(HALL_OF_FAME_ROWS);
+        Self { selection: 0, runs }
+    }
+}
+
+impl State for HallOfFameMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        _state: &mut Self::State,
+        _pop_result: &Option<Self::StateResult>,
+    ) -> ModeReturn {
+        if let Some(key) = term.key {
+            match key {
+                VirtualKeyCode::Escape => {
+                    return (
+                        Transition::Pop(HallOfFameModeResult::Cancelled.into()),
+                        TransitionControl::Update,
+                    );
+                }
+                _ if self.runs.is_empty() => {}
+                VirtualKeyCode::Up => {
+                    self.selection = (self.selection + self.runs.len() - 1) % self.runs.len()
+                }
+                VirtualKeyCode::Down => self.selection = (self.selection + 1) % self.runs.len(),
+                VirtualKeyCode::Return => {
+                    let seed = self.runs[self.selection].seed;
+                    return (
+                        Transition::Pop(HallOfFameModeResult::PlaySeed(seed).into()),
+                        TransitionControl::Immediate,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, _state: &mut Self::State, _active: bool) {
+        let mut batch = DrawBatch::new();
+        batch.target(LAYER_TEXT);
+
+        let height = HALL_OF_FAME_ROWS as i32 + 6;
+        let box_rect = center_box_with_title(
+            &mut batch,
+            (UI_WIDTH, UI_HEIGHT),
+            BoxConfigWithTitle::new(
+                BoxConfig::new((HALL_OF_FAME_WIDTH, height), ColorPair::new(WHITE, BLACK), true, false),
+                TextConfig::with_footer(
+                    "< Hall of Fame >",
+                    "[Enter] play this seed again  [Esc] back",
+                    ColorPair::new(GOLD, BLACK),
+                    ColorPair::new(YELLOW, BLACK),
+                    Alignment::Center,
+                    false,
+                ),
+            ),
+        );
+
+        let x = box_rect.x1 + 2;
+        let mut y = box_rect.y1 + 2;
+        batch.print_color(
+            Point::new(x, y),
+            format!(
+                "{:>3} {:>6}  {:<12} {:>5} {:>6} {:>5}  {:<10}  {}",
+                "#", "Score", "Name", "Depth", "Turns", "Kills", "Date", "Fate"
+            ),
+            ColorPair::new(CYAN, BLACK),
+        );
+        y += 2;
+
+        if self.runs.is_empty() {
+            batch.print_color_centered_at(
+                Point::new(box_rect.x1 + box_rect.width() / 2, box_rect.y1 + box_rect.height() / 2),
+                "-- No runs yet. Go and die heroically. --",
+                ColorPair::new(GRAY, BLACK),
+            );
+        }
+
+        let fate_width = (box_rect.x2 - x - 58).max(0) as usize;
+        for (i, run) in self.runs.iter().enumerate() {
+            let bg = if i == self.selection { SELECTED_BG } else { BLACK };
+            let fate = run.cause_of_death.chars().take(fate_width).collect::<String>();
+
+            batch.print_color(
+                Point::new(x, y + i as i32),
+                format!(
+                    "{:>3} {:>6}  {:<12.12} {:>5} {:>6} {:>5}  {:<10}  {}",
+                    i + 1,
+                    run.score,
+                    run.name,
+                    run.depth,
+                    run.turns,
+                    run.kills,
+                    run.date(),
+                    fate
+                ),
+                ColorPair::new(WHITE, bg),
+            );
+        }
+
+        batch.submit(BATCH_UI_INV).expect("Batch error");
+    }
+}
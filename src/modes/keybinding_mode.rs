@@ -0,0 +1,185 @@
+use super::*;
+use crate::keybindings::{KeyBindings, KeyPreset};
+
+const KEYBINDING_WIDTH: i32 = UI_WIDTH - 4;
+const KEYBINDING_HEIGHT: i32 = 22;
+/// Actions are listed down two columns
+const KEYBINDING_ROWS: usize = (GameKey::ALL.len() + 1) / 2;
+const KEYBINDING_COLUMN_WIDTH: i32 = KEYBINDING_WIDTH / 2 - 2;
+
+#[derive(Debug)]
+pub enum KeyBindingModeResult {
+    Closed,
+}
+
+#[derive(Debug)]
+pub struct KeyBindingMode {
+    bindings: KeyBindings,
+    selection: usize,
+    /// Waiting for the key to add to the selected action
+    capturing: bool,
+    message: Option<(String, (u8, u8, u8))>,
+}
+
+/// Lists every action with its keys, and lets them be changed, reset to a preset and saved.
+impl KeyBindingMode {
+    pub fn new() -> Self {
+        Self { bindings: keybindings::current_bindings(), selection: 0, capturing: false, message: None }
+    }
+
+    fn selected(&self) -> GameKey { GameKey::ALL[self.selection] }
+
+    fn capture(&mut self, key: VirtualKeyCode) {
+        self.capturing = false;
+        if key == VirtualKeyCode::Escape {
+            self.message = None;
+            return;
+        }
+
+        // Keys without a name in the config file couldn't be saved, so they can't be bound either
+        if keybindings::key_name(key).is_none() {
+            self.message = Some((format!("{} can't be bound, pick another key", key_label(key)), RED));
+            return;
+        }
+
+        let action = self.selected();
+        self.bindings.bind(action, key);
+
+        let name = key_label(key);
+        self.message = match self.bindings.conflicts_with(action).iter().find(|(k, _)| *k == key) {
+            Some((_, other)) => Some((format!("{} is also bound to {}", name, other.label()), RED)),
+            None => Some((format!("{} now does {}", name, action.label()), GREEN)),
+        };
+    }
+
+    fn save(&mut self) {
+        if let Some(action) = self.bindings.unbound_required().first() {
+            self.message = Some((format!("Can't save, {} needs a key", action.label()), RED));
+            return;
+        }
+
+        if let Some((key, actions)) = self.bindings.conflicts().first() {
+            let actions = actions.iter().map(|action| action.label()).collect::<Vec<_>>().join(", ");
+            self.message = Some((format!("Can't save, {} is bound to {}", key_label(*key), actions), RED));
+            return;
+        }
+
+        keybindings::set_bindings(self.bindings.clone());
+        self.message = match keybindings::save_keybindings(&self.bindings) {
+            Ok(()) => Some(("Key bindings saved".to_string(), GREEN)),
+            Err(e) => {
+                log::warn!("keybinding_mode::save: {}", e);
+                Some(("Key bindings are in use, but couldn't be saved".to_string(), RED))
+            }
+        };
+    }
+
+    fn apply_preset(&mut self, preset: KeyPreset) {
+        self.bindings = preset.bindings();
+        self.message = Some((format!("Switched to the {} preset", preset.label()), GREEN));
+    }
+}
+
+fn key_label(key: VirtualKeyCode) -> String {
+    keybindings::key_name(key).map_or_else(|| format!("{:?}", key), str::to_string)
+}
+
+impl State for KeyBindingMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        _state: &mut Self::State,
+        _pop_result: &Option<Self::StateResult>,
+    ) -> ModeReturn {
+        if let Some(key) = term.key {
+            if self.capturing {
+                self.capture(key);
+                return (Transition::Stay, TransitionControl::Update);
+            }
+
+            let count = GameKey::ALL.len();
+            match key {
+                VirtualKeyCode::Escape => {
+                    return (Transition::Pop(KeyBindingModeResult::Closed.into()), TransitionControl::Update);
+                }
+                VirtualKeyCode::Up => self.selection = (self.selection + count - 1) % count,
+                VirtualKeyCode::Down => self.selection = (self.selection + 1) % count,
+                VirtualKeyCode::Left => self.selection = self.selection.saturating_sub(KEYBINDING_ROWS),
+                VirtualKeyCode::Right => self.selection = (self.selection + KEYBINDING_ROWS).min(count - 1),
+                VirtualKeyCode::Return => {
+                    self.capturing = true;
+                    self.message = Some((format!("Press a key for {}", self.selected().label()), YELLOW));
+                }
+                VirtualKeyCode::Back | VirtualKeyCode::Delete => {
+                    self.bindings.clear(self.selected());
+                    self.message = None;
+                }
+                VirtualKeyCode::Key1 => self.apply_preset(KeyPreset::ViKeys),
+                VirtualKeyCode::Key2 => self.apply_preset(KeyPreset::Numpad),
+                VirtualKeyCode::Key3 => self.apply_preset(KeyPreset::Wasd),
+                VirtualKeyCode::S => self.save(),
+                _ => {}
+            }
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, _state: &mut Self::State, _active: bool) {
+        let mut batch = DrawBatch::new();
+        batch.target(LAYER_TEXT);
+
+        let box_rect = center_box_with_title(
+            &mut batch,
+            (UI_WIDTH, UI_HEIGHT),
+            BoxConfigWithTitle::new(
+                BoxConfig::new(
+                    (KEYBINDING_WIDTH, KEYBINDING_HEIGHT),
+                    ColorPair::new(WHITE, BLACK),
+                    true,
+                    false,
+                ),
+                TextConfig::with_footer(
+                    "< Key Bindings >",
+                    "[Enter] add key  [Del] clear  [1] vi-keys  [2] numpad  [3] WASD  [S] save  [Esc] back",
+                    ColorPair::new(CYAN, BLACK),
+                    ColorPair::new(YELLOW, BLACK),
+                    Alignment::Center,
+                    false,
+                ),
+            ),
+        );
+
+        let conflicted =
+            self.bindings.conflicts().into_iter().flat_map(|(_, actions)| actions).collect::<Vec<_>>();
+
+        for (i, action) in GameKey::ALL.iter().enumerate() {
+            let column = (i / KEYBINDING_ROWS) as i32;
+            let row = (i % KEYBINDING_ROWS) as i32;
+            let x = box_rect.x1 + 2 + column * (KEYBINDING_COLUMN_WIDTH + 2);
+            let pt = Point::new(x, box_rect.y1 + 2 + row);
+
+            let keys = self.bindings.keys(*action).iter().map(|key| key_label(*key)).collect::<Vec<_>>();
+            let keys = if keys.is_empty() { "-".to_string() } else { keys.join(", ") };
+            let width = KEYBINDING_COLUMN_WIDTH as usize;
+            let text = format!("{:<16} {}", action.label(), keys).chars().take(width).collect::<String>();
+
+            let fg = if conflicted.contains(action) { RED } else { WHITE };
+            let bg = if i == self.selection { SELECTED_BG } else { BLACK };
+            batch.print_color(pt, format!("{:<width$}", text, width = width), ColorPair::new(fg, bg));
+        }
+
+        if let Some((message, color)) = &self.message {
+            batch.print_color_centered_at(
+                Point::new(box_rect.x1 + box_rect.width() / 2, box_rect.y2 - 2),
+                message,
+                ColorPair::new(*color, BLACK),
+            );
+        }
+
+        batch.submit(BATCH_UI_INV).expect("Batch error");
+    }
+}
@@ -0,0 +1,233 @@
+use super::*;
+
+const LOOK_PANEL_WIDTH: i32 = 40;
+
+#[derive(Debug)]
+pub enum LookModeResult {
+    Closed,
+}
+
+#[derive(Debug)]
+pub struct LookMode {
+    cursor: Point,
+    camera: CameraView,
+    /// Where everything in sight stands, nearest first, for Tab to step through
+    targets: Vec<Point>,
+    target_index: Option<usize>,
+}
+
+/// Moves a cursor around the map from the keyboard, describing whatever is under it.
+impl LookMode {
+    pub fn new(world: &mut World) -> Self {
+        let player_pos = *world.resource::<Point>();
+        let player = *world.resource::<Entity>();
+
+        let mut targets = Vec::new();
+        let mut target_q = world.query_filtered::<(Entity, &Point), (
+            With<Naming>,
+            Without<Hidden>,
+            Without<ParticleLifetime>,
+        )>();
+        let map = world.resource::<Map>();
+        for (entity, pos) in target_q.iter(world) {
+            if entity != player && map.visible.get_bit(*pos) && !targets.contains(pos) {
+                targets.push(*pos);
+            }
+        }
+        targets.sort_by(|a, b| {
+            let distance_a = DistanceAlg::Pythagoras.distance2d(player_pos, *a);
+            let distance_b = DistanceAlg::Pythagoras.distance2d(player_pos, *b);
+            distance_a.partial_cmp(&distance_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Self { cursor: player_pos, camera: *world.resource::<CameraView>(), targets, target_index: None }
+    }
+
+    fn move_cursor(&mut self, delta: Point) {
+        let destination = self.cursor + delta;
+        if self.camera.viewport.point_in_rect(destination) {
+            self.cursor = destination;
+        }
+    }
+
+    fn next_target(&mut self) {
+        if self.targets.is_empty() {
+            return;
+        }
+
+        let index = self.target_index.map_or(0, |index| (index + 1) % self.targets.len());
+        self.target_index = Some(index);
+        self.cursor = self.targets[index];
+    }
+
+    /// Everything known about what stands on the cursor, as lines of colored text
+    fn describe_cursor(&self, world: &mut World) -> Vec<(RGB, String)> {
+        let map = world.resource::<Map>();
+        if !map.in_bounds(self.cursor) {
+            return Vec::new();
+        }
+        if !map.visible.get_bit(self.cursor) {
+            let seen_before = map.revealed.get_bit(self.cursor);
+            let text = if seen_before { "You can't see there now." } else { "Unexplored." };
+            return vec![(RGB::named(GRAY), text.to_string())];
+        }
+
+        let mut entity_q = world.query_filtered::<(Entity, &Point, &Glyph), (
+            With<Naming>,
+            Without<Hidden>,
+            Without<ParticleLifetime>,
+        )>();
+        let mut entities = entity_q
+            .iter(world)
+            .filter(|(_, pos, _)| **pos == self.cursor)
+            .map(|(entity, _, glyph)| (entity, glyph.render_order))
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| a.1.cmp(&b.1));
+
+        if entities.is_empty() {
+            return vec![(RGB::named(GRAY), "Nothing of interest.".to_string())];
+        }
+
+        let mut lines = Vec::new();
+        for (entity, _) in entities {
+            if !lines.is_empty() {
+                lines.push((RGB::named(BLACK), String::new()));
+            }
+            lines.append(&mut describe_entity(world, entity));
+        }
+        lines
+    }
+}
+
+fn describe_entity(world: &mut World, entity: Entity) -> Vec<(RGB, String)> {
+    let mut lines = Vec::new();
+
+    let name = world.get::<Naming>(entity).map_or_else(String::new, |name| name.0.clone());
+    let name_color = world.get::<Glyph>(entity).map_or(RGB::named(CYAN), |glyph| glyph.color.fg.to_rgb());
+    lines.push((name_color, name));
+
+    if let Some(desc) = world.get::<Description>(entity) {
+        lines.push((RGB::named(GRAY), desc.0.clone()));
+    }
+
+    if let Some(stats) = world.get::<CombatStats>(entity) {
+        let (health, color) = stats.health_estimate();
+        lines.push((RGB::named(color), health.to_string()));
+    }
+
+    // Status effects
+    let mut statuses = Vec::new();
+    if world.get::<Companion>(entity).is_some() {
+        statuses.push("companion".to_string());
+    }
+    if let Some(confusion) = world.get::<Confusion>(entity) {
+        statuses.push(format!("confused ({} turns)", confusion.turns));
+    }
+    if let Some(sick) = world.get::<Sick>(entity) {
+        statuses.push(format!("sick ({} turns)", sick.turns));
+    }
+    if world.get::<Slowed>(entity).is_some() {
+        statuses.push("slowed".to_string());
+    }
+    if world.get::<Flying>(entity).is_some() {
+        statuses.push("flying".to_string());
+    }
+    if let Some(clock) = world.get::<HungerClock>(entity) {
+        match clock.state {
+            HungerState::WellFed => statuses.push("well fed".to_string()),
+            HungerState::Hungry => statuses.push("hungry".to_string()),
+            HungerState::Starving => statuses.push("starving".to_string()),
+            HungerState::Normal => {}
+        }
+    }
+    if !statuses.is_empty() {
+        lines.push((RGB::named(YELLOW), statuses.join(", ")));
+    }
+
+    // Equipment
+    let mut equipped_q = world.query::<(&Equipped, &Naming)>();
+    for (equipped, name) in equipped_q.iter(world) {
+        if equipped.owner == entity {
+            lines.push((RGB::named(LIGHTGRAY), format!("Equipped: {}", name.0)));
+        }
+    }
+
+    lines
+}
+
+impl State for LookMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        _state: &mut Self::State,
+        _pop_result: &Option<Self::StateResult>,
+    ) -> ModeReturn {
+        if term.key == Some(VirtualKeyCode::Tab) {
+            self.next_target();
+            return (Transition::Stay, TransitionControl::Update);
+        }
+
+        match term.get_key() {
+            Some(GameKey::Escape) | Some(GameKey::Look) => {
+                return (Transition::Pop(LookModeResult::Closed.into()), TransitionControl::Update);
+            }
+            Some(GameKey::Left) => self.move_cursor(Point::new(-1, 0)),
+            Some(GameKey::Right) => self.move_cursor(Point::new(1, 0)),
+            Some(GameKey::Up) => self.move_cursor(Point::new(0, -1)),
+            Some(GameKey::Down) => self.move_cursor(Point::new(0, 1)),
+            Some(GameKey::LeftUp) => self.move_cursor(Point::new(-1, -1)),
+            Some(GameKey::RightUp) => self.move_cursor(Point::new(1, -1)),
+            Some(GameKey::LeftDown) => self.move_cursor(Point::new(-1, 1)),
+            Some(GameKey::RightDown) => self.move_cursor(Point::new(1, 1)),
+            _ => {}
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, state: &mut Self::State, _active: bool) {
+        state.render_schedule.run(&mut state.app.world);
+        gui::render_ui(&mut state.app.world);
+
+        let mut batch = DrawBatch::new();
+        batch.target(LAYER_ZERO);
+        let cursor_screen_pt = self.camera.world_to_screen(self.cursor);
+        batch.set_bg(cursor_screen_pt, SELECTED_BG);
+        batch.submit(BATCH_UI).expect("Batch error");
+
+        let mut lines = self.describe_cursor(&mut state.app.world);
+        let max_len = LOOK_PANEL_WIDTH as usize - 2;
+        lines.iter_mut().for_each(|(_, text)| *text = text.chars().take(max_len).collect());
+
+        // The panel keeps to the half of the map away from the cursor
+        let mut batch = DrawBatch::new();
+        batch.target(LAYER_TEXT);
+
+        let height = lines.len() as i32 + 1;
+        let panel_x = if cursor_screen_pt.x * 2 < MAP_PANEL.width() / 2 {
+            MAP_PANEL.x2 - LOOK_PANEL_WIDTH - 1
+        } else {
+            MAP_PANEL.x1 + 1
+        };
+        let panel = Rect::with_size(panel_x, MAP_PANEL.y1 + 1, LOOK_PANEL_WIDTH, height);
+
+        batch.draw_box(panel, ColorPair::new(WHITE, BLACK));
+        batch.print_color(Point::new(panel.x1 + 1, panel.y1), "< Look >", ColorPair::new(CYAN, BLACK));
+        for (i, (color, text)) in lines.iter().enumerate() {
+            let pt = Point::new(panel.x1 + 1, panel.y1 + 1 + i as i32);
+            batch.print_color(pt, text, ColorPair::new(*color, BLACK));
+        }
+        batch.print_color(
+            Point::new(panel.x1 + 1, panel.y2),
+            "[Tab] next  [Esc] done",
+            ColorPair::new(YELLOW, BLACK),
+        );
+
+        batch.submit(BATCH_TOOLTIPS).expect("Batch error");
+    }
+
+    fn draw_behind(&self) -> bool { false }
+}
@@ -17,6 +17,8 @@ pub enum MainMenuModeResult {
 #[derive(Debug)]
 pub enum MainMenuAction {
     NewGame,
+    HallOfFame,
+    KeyBindings,
     Quit,
 }
 
@@ -24,6 +26,8 @@ impl MainMenuAction {
     fn label(&self) -> &'static str {
         match self {
             MainMenuAction::NewGame => "New Game",
+            MainMenuAction::HallOfFame => "Hall of Fame",
+            MainMenuAction::KeyBindings => "Key Bindings",
             MainMenuAction::Quit => "Quit",
         }
     }
@@ -40,7 +44,8 @@ pub struct MainMenuMode {
 /// Show the title screen of the game with a menu that leads into the game proper.
 impl MainMenuMode {
     pub fn new() -> Self {
-        let mut actions = vec![MainMenuAction::NewGame];
+        let mut actions =
+            vec![MainMenuAction::NewGame, MainMenuAction::HallOfFame, MainMenuAction::KeyBindings];
 
         #[cfg(not(target_arch = "wasm32"))]
         actions.push(MainMenuAction::Quit);
@@ -57,10 +62,17 @@ impl State for MainMenuMode {
         &mut self,
         term: &mut BTerm,
         state: &mut Self::State,
-        _pop_result: &Option<Self::StateResult>,
+        pop_result: &Option<Self::StateResult>,
     ) -> ModeReturn {
         state.app.update();
 
+        if let Some(ModeResult::HallOfFameModeResult(HallOfFameModeResult::PlaySeed(seed))) = pop_result {
+            return (
+                Transition::Switch(DungeonMode::new(&mut state.app, *seed).boxed()),
+                TransitionControl::Immediate,
+            );
+        }
+
         if let Some(key) = term.key {
             match key {
                 VirtualKeyCode::Escape => {
@@ -95,10 +107,22 @@ impl State for MainMenuMode {
                         }
                         MainMenuAction::NewGame => {
                             return (
-                                Transition::Switch(DungeonMode::new(&mut state.app).boxed()),
+                                Transition::Switch(DungeonMode::new(&mut state.app, rng::new_seed()).boxed()),
                                 TransitionControl::Immediate,
                             );
                         }
+                        MainMenuAction::HallOfFame => {
+                            return (
+                                Transition::Push(HallOfFameMode::new().boxed()),
+                                TransitionControl::Update,
+                            );
+                        }
+                        MainMenuAction::KeyBindings => {
+                            return (
+                                Transition::Push(KeyBindingMode::new().boxed()),
+                                TransitionControl::Update,
+                            );
+                        }
                     }
                 }
                 _ => {}
@@ -4,11 +4,18 @@ use bevy::ecs::system::CommandQueue;
 fn setup_new_game(world: &mut World) {
     world.clear_entities();
 
+    // The same seed always digs the same dungeon
+    let seed = world.get_resource::<RunSeed>().map_or_else(rng::new_seed, |seed| seed.0);
+    rng::reseed(seed);
+
     world.insert_resource(ParticleBuilder::new());
     world.insert_resource(MasterDungeonMap::new());
     world.insert_resource(Map::new(0, 64, 64, "Dummy Map"));
+    world.insert_resource(raws::get_hunger_config(&RAWS.lock()));
+    world.insert_resource(RunStats::new());
 
     transition_to_new_map(world, 1);
+    world.resource_mut::<RunStats>().record_depth(1);
 
     bo_logging::clear_log();
     bo_logging::clear_events();
@@ -30,6 +37,9 @@ fn goto_level(world: &mut World) {
         transition_to_new_map(world, new_depth)
     }
 
+    gather_companions(world, new_depth - offset);
+    world.resource_mut::<RunStats>().record_depth(new_depth);
+
     // Notify the player
     bo_logging::Logger::new().append("You change level.").log();
 }
@@ -40,16 +50,17 @@ fn goto_level(world: &mut World) {
 
 pub fn freeze_level_entities(world: &mut World) {
     // Obtain ECS access
-    let mut positions = world.query::<(Entity, &Point)>();
+    let mut positions = world.query::<(Entity, &Point, Option<&Companion>)>();
     let map_depth = world.resource::<Map>().depth;
     let player_entity = world.resource::<Entity>();
 
     let mut queue = CommandQueue::default();
     let mut commands = Commands::new(&mut queue, world);
 
-    // Find positions and make OtherLevelPosition
-    for (entity, pos) in positions.iter(world) {
-        if entity != *player_entity {
+    // Find positions and make OtherLevelPosition. Companions that aren't told to stay come along.
+    for (entity, pos, companion) in positions.iter(world) {
+        let follows = companion.map_or(false, |c| c.order != CompanionOrder::Stay);
+        if entity != *player_entity && !follows {
             commands.entity(entity).remove::<Point>();
             commands.entity(entity).insert(OtherLevelPosition::new(*pos, map_depth));
         }
@@ -79,6 +90,51 @@ pub fn thaw_level_entities(world: &mut World) {
     queue.apply(world);
 }
 
+/// Places the companions that followed the player on the free tiles closest to them
+fn gather_companions(world: &mut World, previous_depth: i32) {
+    let player_pos = *world.resource::<Point>();
+    let player_entity = *world.resource::<Entity>();
+
+    let mut occupied = world.query_filtered::<&Point, Without<Companion>>().iter(world).copied().collect::<Vec<_>>();
+    let companions = world
+        .query_filtered::<Entity, (With<Companion>, With<Point>)>()
+        .iter(world)
+        .filter(|entity| *entity != player_entity)
+        .collect::<Vec<_>>();
+
+    for companion in companions {
+        let destination = {
+            let map = world.resource::<Map>();
+            (1..=4).find_map(|radius| {
+                (-radius..=radius)
+                    .flat_map(|y| (-radius..=radius).map(move |x| player_pos + Point::new(x, y)))
+                    .find(|pt| {
+                        map.in_bounds(*pt) && map.tiles[map.point2d_to_index(*pt)].walkable && !occupied.contains(pt)
+                    })
+            })
+        };
+
+        match destination {
+            Some(pt) => {
+                occupied.push(pt);
+                world.entity_mut(companion).insert(pt);
+
+                if let Some(mut companion) = world.get_mut::<Companion>(companion) {
+                    companion.order = CompanionOrder::Follow;
+                }
+                if let Some(mut fov) = world.get_mut::<FieldOfView>(companion) {
+                    fov.is_dirty = true;
+                }
+            }
+            // Nowhere to stand, so they wait where they were on the old level
+            None => {
+                let pt = *world.get::<Point>(companion).unwrap();
+                world.entity_mut(companion).remove::<Point>().insert(OtherLevelPosition::new(pt, previous_depth));
+            }
+        }
+    }
+}
+
 fn transition_to_new_map(world: &mut World, new_depth: i32) {
     let mut builder = map_builders::level_builder(new_depth, 80, 50);
     builder.build_map();
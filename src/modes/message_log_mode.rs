@@ -0,0 +1,201 @@
+use super::*;
+
+const MESSAGE_LOG_WIDTH: i32 = UI_WIDTH - 4;
+const MESSAGE_LOG_HEIGHT: i32 = UI_HEIGHT - 2;
+/// Rows taken by the border, the filter line and the gap under it
+const MESSAGE_LOG_CHROME: i32 = 4;
+const LOG_EXPORT_FILENAME: &str = "message_log.jsonl";
+
+#[derive(Debug)]
+pub enum MessageLogModeResult {
+    Closed,
+}
+
+#[derive(Debug, Default)]
+pub struct MessageLogMode {
+    /// How many entries up from the newest the view has been scrolled
+    scroll: usize,
+    category: Option<LogCategory>,
+    search: String,
+    searching: bool,
+}
+
+/// Every message still in the log, newest at the bottom, with filters for the kind of message and its text.
+impl MessageLogMode {
+    pub fn new() -> Self { Self::default() }
+
+    fn page_size() -> usize { (MESSAGE_LOG_HEIGHT - MESSAGE_LOG_CHROME) as usize }
+
+    fn entries(&self) -> Vec<LogEntry> {
+        let search = self.search.to_lowercase();
+        bo_logging::log_entries(self.category)
+            .into_iter()
+            .filter(|entry| search.is_empty() || entry.text().to_lowercase().contains(&search))
+            .collect()
+    }
+
+    /// Steps through every category and back round to showing everything
+    fn next_category(&mut self) {
+        self.category = match self.category {
+            None => Some(LogCategory::ALL[0]),
+            Some(current) => {
+                let index = LogCategory::ALL.iter().position(|c| *c == current).unwrap_or(0);
+                LogCategory::ALL.get(index + 1).copied()
+            }
+        };
+        self.scroll = 0;
+    }
+
+    fn category_label(&self) -> &'static str { self.category.map_or("All", |category| category.label()) }
+
+    /// Writes the messages being shown, minus the text search, to a JSON lines file
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export(&self) {
+        let result = bo_logging::log_to_json_lines(self.category)
+            .map_err(BoxedError::from)
+            .and_then(|lines| Ok(std::fs::write(LOG_EXPORT_FILENAME, lines)?));
+
+        match result {
+            Ok(()) => {
+                bo_logging::Logger::new().append("Messages exported to").append(LOG_EXPORT_FILENAME).log()
+            }
+            Err(e) => log::warn!("message_log_mode::export: {}", e),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn export(&self) {}
+
+    fn update_search(&mut self, key: VirtualKeyCode) {
+        match key {
+            VirtualKeyCode::Escape | VirtualKeyCode::Return => self.searching = false,
+            VirtualKeyCode::Back => {
+                self.search.pop();
+            }
+            VirtualKeyCode::Space => self.search.push(' '),
+            key => {
+                let letter = letter_to_option(key);
+                if letter >= 0 {
+                    self.search.push((b'a' + letter as u8) as char);
+                }
+            }
+        }
+        self.scroll = 0;
+    }
+}
+
+impl State for MessageLogMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        _state: &mut Self::State,
+        _pop_result: &Option<Self::StateResult>,
+    ) -> ModeReturn {
+        if let Some(key) = term.key {
+            if self.searching {
+                self.update_search(key);
+                return (Transition::Stay, TransitionControl::Update);
+            }
+
+            let max_scroll = self.entries().len().saturating_sub(Self::page_size());
+            match key {
+                VirtualKeyCode::Escape => {
+                    return (Transition::Pop(MessageLogModeResult::Closed.into()), TransitionControl::Update);
+                }
+                VirtualKeyCode::Up => self.scroll += 1,
+                VirtualKeyCode::Down => self.scroll = self.scroll.saturating_sub(1),
+                VirtualKeyCode::PageUp => self.scroll += Self::page_size(),
+                VirtualKeyCode::PageDown => self.scroll = self.scroll.saturating_sub(Self::page_size()),
+                VirtualKeyCode::Home => self.scroll = max_scroll,
+                VirtualKeyCode::End => self.scroll = 0,
+                VirtualKeyCode::Tab => self.next_category(),
+                VirtualKeyCode::X => self.export(),
+                VirtualKeyCode::Slash => {
+                    self.searching = true;
+                    self.search.clear();
+                }
+                _ => {}
+            }
+            self.scroll = self.scroll.min(max_scroll);
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, _state: &mut Self::State, _active: bool) {
+        let mut batch = DrawBatch::new();
+        batch.target(LAYER_TEXT);
+
+        let box_rect = center_box_with_title(
+            &mut batch,
+            (UI_WIDTH, UI_HEIGHT),
+            BoxConfigWithTitle::new(
+                BoxConfig::new(
+                    (MESSAGE_LOG_WIDTH, MESSAGE_LOG_HEIGHT),
+                    ColorPair::new(BOX_GRAY, BLACK),
+                    false,
+                    false,
+                ),
+                TextConfig::with_footer(
+                    "< Message Log >",
+                    "[Up/Down/PgUp/PgDn] scroll  [Tab] filter  [/] search  [X] export  [Esc] close",
+                    ColorPair::new(CYAN, BLACK),
+                    ColorPair::new(YELLOW, BLACK),
+                    Alignment::Left,
+                    false,
+                ),
+            ),
+        );
+
+        let x = box_rect.x1 + 2;
+        let search = if self.searching { format!("{}_", self.search) } else { self.search.clone() };
+        batch.print_color(
+            Point::new(x, box_rect.y1 + 1),
+            format!("Showing: {:<8} Search: {}", self.category_label(), search),
+            ColorPair::new(GRAY, BLACK),
+        );
+
+        // The newest entries sit at the bottom, scrolling walks back up through older ones
+        let entries = self.entries();
+        let end = entries.len() - self.scroll.min(entries.len());
+        let start = end.saturating_sub(Self::page_size());
+        let max_x = box_rect.x2 - 1;
+
+        for (row, entry) in entries[start..end].iter().enumerate() {
+            let y = box_rect.y1 + 3 + row as i32;
+            let turn = format!("[{:>5}] ", entry.turn);
+            batch.print_color(Point::new(x, y), &turn, ColorPair::new(DARK_GRAY, BLACK));
+
+            let mut fragment_x = x + turn.len() as i32;
+            for fragment in entry.fragments.iter() {
+                let room = (max_x - fragment_x).max(0) as usize;
+                if room == 0 {
+                    break;
+                }
+
+                let text = fragment.text.chars().take(room).collect::<String>();
+                batch.print_color(Point::new(fragment_x, y), &text, ColorPair::new(fragment.color, BLACK));
+                fragment_x += text.len() as i32 + 1;
+            }
+
+            if let Some(suffix) = entry.repeat_suffix() {
+                if fragment_x + (suffix.len() as i32) < max_x {
+                    batch.print_color(Point::new(fragment_x, y), &suffix, ColorPair::new(GRAY, BLACK));
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            batch.print_color_centered_at(
+                Point::new(box_rect.x1 + box_rect.width() / 2, box_rect.y1 + box_rect.height() / 2),
+                "-- No Messages --",
+                ColorPair::new(WHITE, BLACK),
+            );
+        }
+
+        batch.submit(BATCH_UI_INV).expect("Batch error");
+    }
+}
@@ -6,19 +6,29 @@ pub type ModeReturn = StateReturn<GameWorld, ModeResult>;
 pub mod dialogs;
 pub mod dungeon_mode;
 pub mod game_over_mode;
+pub mod hall_of_fame_mode;
 pub mod inventory_mode;
+pub mod keybinding_mode;
+pub mod look_mode;
 pub mod main_menu_mode;
 pub mod map_gen;
 pub mod menu_memory;
+pub mod message_log_mode;
+pub mod spellbook_mode;
 pub mod targeting_mode;
 
 use dungeon_mode::{DungeonMode, DungeonModeResult};
 use game_over_mode::GameOverModeResult;
+use hall_of_fame_mode::{HallOfFameMode, HallOfFameModeResult};
 use inventory_mode::EquipmentActionModeResult;
 use inventory_mode::InventoryActionModeResult;
 use inventory_mode::InventoryModeResult;
+use keybinding_mode::{KeyBindingMode, KeyBindingModeResult};
+use look_mode::{LookMode, LookModeResult};
 use main_menu_mode::MainMenuModeResult;
 pub use menu_memory::MenuMemory;
+use message_log_mode::{MessageLogMode, MessageLogModeResult};
+use spellbook_mode::{SpellbookMode, SpellbookModeResult};
 use targeting_mode::{TargetingMode, TargetingModeResult};
 
 use dialogs::*;
@@ -28,9 +38,15 @@ pub enum ModeResult {
     DungeonModeResult(DungeonModeResult),
     MainMenuModeResult(MainMenuModeResult),
     GameOverModeResult(GameOverModeResult),
+    HallOfFameModeResult(HallOfFameModeResult),
+    KeyBindingModeResult(KeyBindingModeResult),
     InventoryModeResult(InventoryModeResult),
     TargetingModeResult(TargetingModeResult),
+    SpellbookModeResult(SpellbookModeResult),
+    MessageLogModeResult(MessageLogModeResult),
+    LookModeResult(LookModeResult),
     MessageBoxModeResult(MessageBoxModeResult),
+    OrdersDialogModeResult(OrdersDialogModeResult),
     YesNoDialogModeResult(YesNoDialogModeResult),
     AppQuitDialogModeResult(AppQuitDialogModeResult),
     InventoryActionModeResult(InventoryActionModeResult),
@@ -40,9 +56,15 @@ pub enum ModeResult {
 impl_from!(ModeResult, DungeonModeResult);
 impl_from!(ModeResult, MainMenuModeResult);
 impl_from!(ModeResult, GameOverModeResult);
+impl_from!(ModeResult, HallOfFameModeResult);
+impl_from!(ModeResult, KeyBindingModeResult);
 impl_from!(ModeResult, InventoryModeResult);
 impl_from!(ModeResult, TargetingModeResult);
+impl_from!(ModeResult, SpellbookModeResult);
+impl_from!(ModeResult, MessageLogModeResult);
+impl_from!(ModeResult, LookModeResult);
 impl_from!(ModeResult, MessageBoxModeResult);
+impl_from!(ModeResult, OrdersDialogModeResult);
 impl_from!(ModeResult, YesNoDialogModeResult);
 impl_from!(ModeResult, AppQuitDialogModeResult);
 impl_from!(ModeResult, InventoryActionModeResult);
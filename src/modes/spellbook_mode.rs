@@ -0,0 +1,176 @@
+use super::*;
+use std::collections::HashMap;
+
+const SPELLBOOK_BASE_WIDTH: i32 = 25;
+
+#[derive(Debug)]
+pub enum SpellbookModeResult {
+    Cancelled,
+    CastSpell(Entity, Option<Point>),
+}
+
+#[derive(Debug)]
+struct SpellEntry {
+    spell: Entity,
+    label: String,
+    castable: bool,
+}
+
+#[derive(Debug)]
+pub struct SpellbookMode {
+    selection: usize,
+    width: i32,
+    spells: Vec<SpellEntry>,
+}
+
+/// Show the spells the player knows, and cast the chosen one.
+impl SpellbookMode {
+    pub fn new(world: &mut World) -> Self {
+        let player = *world.resource::<Entity>();
+        let mana = world.get::<Mana>(player).map_or(0, |mana| mana.current);
+        let known = world.get::<KnownSpells>(player).cloned().unwrap_or_default();
+
+        let mut template_q = world.query_filtered::<(Entity, &Naming), With<SpellTemplate>>();
+        let templates =
+            template_q.iter(world).map(|(spell, name)| (name.0.clone(), spell)).collect::<HashMap<_, _>>();
+
+        let spells = known
+            .spells
+            .iter()
+            .filter_map(|known| {
+                templates.get(&known.name).map(|spell| SpellEntry {
+                    spell: *spell,
+                    label: format!("{} ({} mana)", known.name, known.mana_cost),
+                    castable: known.mana_cost <= mana,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let width = i32::max(
+            SPELLBOOK_BASE_WIDTH,
+            spells.iter().map(|entry| entry.label.len() as i32 + 8).max().unwrap_or(0),
+        );
+
+        Self { selection: 0, width, spells }
+    }
+
+    fn cast(&self, term: &mut BTerm, world: &World) -> ModeReturn {
+        let entry = &self.spells[self.selection];
+        if !entry.castable {
+            bo_logging::Logger::new().append("You don't have enough mana for that.").log();
+            return (Transition::Stay, TransitionControl::Update);
+        }
+
+        match world.get::<Ranged>(entry.spell) {
+            Some(Ranged(range)) => (
+                Transition::Push(TargetingMode::new(term, world, entry.spell, *range, true).boxed()),
+                TransitionControl::Update,
+            ),
+            None => (
+                Transition::Pop(SpellbookModeResult::CastSpell(entry.spell, None).into()),
+                TransitionControl::Immediate,
+            ),
+        }
+    }
+}
+
+impl State for SpellbookMode {
+    type State = GameWorld;
+    type StateResult = ModeResult;
+
+    fn update(
+        &mut self,
+        term: &mut BTerm,
+        state: &mut Self::State,
+        pop_result: &Option<Self::StateResult>,
+    ) -> StateReturn<Self::State, Self::StateResult> {
+        if let Some(result) = pop_result {
+            return match result {
+                ModeResult::TargetingModeResult(result) => match result {
+                    TargetingModeResult::Cancelled => (Transition::Stay, TransitionControl::Update),
+                    TargetingModeResult::Target(spell, pt) => (
+                        Transition::Pop(SpellbookModeResult::CastSpell(*spell, Some(*pt)).into()),
+                        TransitionControl::Immediate,
+                    ),
+                },
+                _ => (Transition::Stay, TransitionControl::Update),
+            };
+        }
+
+        if let Some(key) = term.key {
+            match key {
+                VirtualKeyCode::Escape => {
+                    return (
+                        Transition::Pop(SpellbookModeResult::Cancelled.into()),
+                        TransitionControl::Update,
+                    );
+                }
+                _ if self.spells.is_empty() => {}
+                VirtualKeyCode::Up => {
+                    self.selection = (self.selection + self.spells.len() - 1) % self.spells.len()
+                }
+                VirtualKeyCode::Down => self.selection = (self.selection + 1) % self.spells.len(),
+                VirtualKeyCode::Return => return self.cast(term, &state.app.world),
+                key => {
+                    // Spells can also be picked by their letter
+                    let index = letter_to_option(key);
+                    if index >= 0 && (index as usize) < self.spells.len() {
+                        self.selection = index as usize;
+                        return self.cast(term, &state.app.world);
+                    }
+                }
+            }
+        }
+
+        (Transition::Stay, TransitionControl::Update)
+    }
+
+    fn render(&mut self, _term: &mut BTerm, _state: &mut Self::State, _active: bool) {
+        let mut draw_batch = DrawBatch::new();
+        draw_batch.target(LAYER_TEXT);
+
+        let height = i32::max(4, self.spells.len() as i32 + 3);
+        let start_x = (MAP_PANEL_WIDTH / 2) - (self.width / 2);
+        let start_y = (MAP_PANEL_HEIGHT / 2) - (height / 2);
+
+        let book_box = box_with_title(
+            &mut draw_batch,
+            Point::new(start_x, start_y),
+            BoxConfigWithTitle::new(
+                BoxConfig::new((self.width, height), ColorPair::new(BOX_GRAY, BLACK), false, false),
+                TextConfig::with_footer(
+                    "< Spellbook >",
+                    "[Esc] to cancel",
+                    ColorPair::new(CYAN, BLACK),
+                    ColorPair::new(YELLOW, BLACK),
+                    Alignment::Left,
+                    false,
+                ),
+            ),
+        );
+
+        if self.spells.is_empty() {
+            draw_batch.print_color_centered_at(
+                Point::new(book_box.x1 + book_box.width() / 2, book_box.y1 + book_box.height() / 2),
+                "-- No Spells --",
+                ColorPair::new(WHITE, BLACK),
+            );
+        }
+
+        for (i, entry) in self.spells.iter().enumerate() {
+            let fg = if entry.castable { WHITE } else { GRAY };
+            let bg = if i == self.selection { SELECTED_BG } else { BLACK };
+            draw_batch.print_color(
+                Point::new(book_box.x1 + 1, book_box.y1 + 2 + i as i32),
+                format!("({}) {}", (b'a' + i as u8) as char, entry.label),
+                ColorPair::new(fg, bg),
+            );
+        }
+
+        draw_batch.submit(BATCH_UI_INV).expect("Batch error"); // On top of everything
+    }
+
+    fn draw_behind(&self) -> bool {
+        true
+    }
+}
@@ -32,6 +32,14 @@ impl MasterTable {
         }
     }
 
+    pub fn roll_category(&self, category: SpawnTableType) -> Option<String> {
+        match category {
+            SpawnTableType::Item => self.items.roll(),
+            SpawnTableType::Mob => self.mobs.roll(),
+            SpawnTableType::Prop => self.props.roll(),
+        }
+    }
+
     pub fn roll(&self) -> Option<String> {
         let roll = crate::rng::roll_dice(1, 4);
         match roll {
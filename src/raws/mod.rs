@@ -15,6 +15,33 @@ embedded_resource!(RAW_ITEM_FILE, "../../resources/raws/items.ron");
 embedded_resource!(RAW_MOB_FILE, "../../resources/raws/mobs.ron");
 embedded_resource!(RAW_PROP_FILE, "../../resources/raws/props.ron");
 embedded_resource!(RAW_SPAWN_TABLE_FILE, "../../resources/raws/spawn_table.ron");
+embedded_resource!(RAW_THEME_FILE, "../../resources/raws/themes.ron");
+embedded_resource!(RAW_TILE_FILE, "../../resources/raws/tiles.ron");
+embedded_resource!(RAW_HUNGER_FILE, "../../resources/raws/hunger.ron");
+embedded_resource!(RAW_SPELL_FILE, "../../resources/raws/spells.ron");
+embedded_resource!(RAW_PREFAB_LEGEND_FILE, "../../resources/raws/prefab_legend.ron");
+
+// Prefabs
+embedded_resource!(PREFAB_TOTALLY_NOT_A_TRAP, "../../resources/prefabs/rooms/totally_not_a_trap.txt");
+embedded_resource!(PREFAB_SILLY_SMILE, "../../resources/prefabs/rooms/silly_smile.txt");
+embedded_resource!(PREFAB_CHECKERBOARD, "../../resources/prefabs/rooms/checkerboard.txt");
+embedded_resource!(PREFAB_UNDERGROUND_FORT, "../../resources/prefabs/sections/underground_fort.txt");
+embedded_resource!(PREFAB_ORC_CAMP, "../../resources/prefabs/sections/orc_camp.txt");
+embedded_resource!(PREFAB_DROW_ENTRY, "../../resources/prefabs/sections/drow_entry.txt");
+embedded_resource!(PREFAB_WFC_POPULATED, "../../resources/prefabs/levels/wfc_populated.txt");
+embedded_resource!(PREFAB_WFC_POPULATED_XP, "../../resources/xp/wfc-populated.xp");
+
+const PREFAB_FILES: &[(&str, &[u8])] = &[
+    ("resources/prefabs/rooms/totally_not_a_trap.txt", PREFAB_TOTALLY_NOT_A_TRAP),
+    ("resources/prefabs/rooms/silly_smile.txt", PREFAB_SILLY_SMILE),
+    ("resources/prefabs/rooms/checkerboard.txt", PREFAB_CHECKERBOARD),
+    ("resources/prefabs/sections/underground_fort.txt", PREFAB_UNDERGROUND_FORT),
+    ("resources/prefabs/sections/orc_camp.txt", PREFAB_ORC_CAMP),
+    ("resources/prefabs/sections/drow_entry.txt", PREFAB_DROW_ENTRY),
+    ("resources/prefabs/levels/wfc_populated.txt", PREFAB_WFC_POPULATED),
+];
+
+const PREFAB_REX_FILES: &[(&str, &[u8])] = &[("resources/xp/wfc-populated.xp", PREFAB_WFC_POPULATED_XP)];
 
 lazy_static! {
     pub static ref RAWS: Mutex<RawMaster> = Mutex::new(RawMaster::empty());
@@ -25,6 +52,12 @@ pub struct Raws {
     pub mobs: Vec<RawMob>,
     pub props: Vec<RawProp>,
     pub items: Vec<RawItem>,
+    pub tiles: Vec<RawTile>,
+    pub hunger: RawHungerConfig,
+    pub spells: Vec<RawSpell>,
+    pub themes: Vec<RawTheme>,
+    pub prefabs: Vec<RawPrefab>,
+    pub prefab_legend: Vec<RawLegendEntry>,
     pub spawn_table: Vec<SpawnTableEntry>,
 }
 
@@ -37,6 +70,20 @@ impl Raws {
         }
     }
 
+    fn load_prefabs() -> Vec<RawPrefab> {
+        let mut prefabs = Vec::new();
+
+        for (path, raw_data) in PREFAB_FILES.iter() {
+            let source = String::from_utf8_lossy(raw_data);
+            match RawPrefab::parse(&source, PREFAB_REX_FILES) {
+                Ok(prefab) => prefabs.push(prefab),
+                Err(e) => log::warn!("Skipping invalid prefab [{}]: {}", path, e),
+            }
+        }
+
+        prefabs
+    }
+
     fn _load_file<'a, T: serde::Deserialize<'a>>(file_path: &str) -> T {
         // Retrieve the raw data as an array of u8 (8-bit unsigned chars)
         let raw_data = EMBED.lock().get_resource(file_path.to_string()).unwrap();
@@ -52,11 +99,33 @@ pub fn load_raws() {
     link_resource!(RAW_MOB_FILE, "resources/raws/mobs.ron");
     link_resource!(RAW_PROP_FILE, "resources/raws/props.ron");
     link_resource!(RAW_SPAWN_TABLE_FILE, "resources/raws/spawn_table.ron");
+    link_resource!(RAW_THEME_FILE, "resources/raws/themes.ron");
+    link_resource!(RAW_TILE_FILE, "resources/raws/tiles.ron");
+    link_resource!(RAW_HUNGER_FILE, "resources/raws/hunger.ron");
+    link_resource!(RAW_SPELL_FILE, "resources/raws/spells.ron");
+    link_resource!(RAW_PREFAB_LEGEND_FILE, "resources/raws/prefab_legend.ron");
 
     let mobs = Raws::load_raw::<Vec<RawMob>>(RAW_MOB_FILE);
     let items = Raws::load_raw::<Vec<RawItem>>(RAW_ITEM_FILE);
     let props = Raws::load_raw::<Vec<RawProp>>(RAW_PROP_FILE);
+    let tiles = Raws::load_raw::<Vec<RawTile>>(RAW_TILE_FILE);
+    let hunger = Raws::load_raw::<RawHungerConfig>(RAW_HUNGER_FILE);
+    let spells = Raws::load_raw::<Vec<RawSpell>>(RAW_SPELL_FILE);
+    let themes = Raws::load_raw::<Vec<RawTheme>>(RAW_THEME_FILE);
     let spawn_table = Raws::load_raw::<Vec<SpawnTableEntry>>(RAW_SPAWN_TABLE_FILE);
+    let prefabs = Raws::load_prefabs();
+    let prefab_legend = Raws::load_raw::<Vec<RawLegendEntry>>(RAW_PREFAB_LEGEND_FILE);
 
-    RAWS.lock().load(Raws { items, mobs, props, spawn_table });
+    RAWS.lock().load(Raws {
+        items,
+        mobs,
+        props,
+        tiles,
+        hunger,
+        spells,
+        themes,
+        prefabs,
+        prefab_legend,
+        spawn_table,
+    });
 }
@@ -14,6 +14,57 @@ impl RawMaster {
         load_entity_data(&self.raws.mobs, &mut self.mob_index, &mut used_names);
         // Props
         load_entity_data(&self.raws.props, &mut self.prop_index, &mut used_names);
+
+        // Lights need a radius to shine at all
+        let lights = self.raws.mobs.iter_mut().map(|mob| (&mob.name, &mut mob.light));
+        let lights = lights.chain(self.raws.props.iter_mut().map(|prop| (&prop.name, &mut prop.light)));
+        for (name, light) in lights {
+            if light.as_ref().map_or(false, |light| light.radius <= 0) {
+                log::warn!("Light without a positive radius in raws [{}], ignoring it", name);
+                *light = None;
+            }
+        }
+
+        // Tiles
+        let mut tiles = HashMap::new();
+        for tile in self.raws.tiles.iter() {
+            if tiles.insert(tile.tile_type, get_game_tile(tile)).is_some() {
+                log::warn!("Duplicate tile type in raws [{:?}]", tile.tile_type);
+            }
+        }
+        crate::set_tile_table(tiles);
+
+        // Themes
+        for (i, theme) in self.raws.themes.iter().enumerate() {
+            if self.theme_index.insert(theme.name.clone(), i).is_some() {
+                log::warn!("Duplicate theme name in raws [{}]", theme.name);
+            }
+        }
+
+        // Spells
+        for (i, spell) in self.raws.spells.iter().enumerate() {
+            if self.spell_index.insert(spell.name.clone(), i).is_some() {
+                log::warn!("Duplicate spell name in raws [{}]", spell.name);
+            }
+        }
+
+        // Prefabs
+        for (i, prefab) in self.raws.prefabs.iter().enumerate() {
+            if self.prefab_index.insert(prefab.name.clone(), i).is_some() {
+                log::warn!("Duplicate prefab name in raws [{}]", prefab.name);
+            }
+        }
+
+        // Legend spawns have to name an item, mob or prop
+        let shared_legend = self.raws.prefab_legend.iter().map(|entry| ("prefab legend", entry));
+        let prefab_legends = self.raws.prefabs.iter().flat_map(|prefab| {
+            prefab.legend.values().map(move |entry| (prefab.name.as_str(), entry))
+        });
+        for (source, entry) in shared_legend.chain(prefab_legends) {
+            if let Some(spawn) = entry.spawn.as_ref().filter(|spawn| !used_names.contains(*spawn)) {
+                log::warn!("Unknown spawn in raws [{}] for '{}' in [{}]", spawn, entry.glyph, source);
+            }
+        }
     }
 }
 
@@ -26,7 +77,7 @@ pub fn load_entity_data<T: 'static + BaseRawComponent>(
         let entity_name = entity.name();
 
         if used_names.contains(&entity_name) {
-            println!("WARNING - duplicate entity name in raws [{}]", entity_name);
+            log::warn!("Duplicate entity name in raws [{}]", entity_name);
         }
 
         entiy_index.insert(entity_name.clone(), i);
@@ -1,5 +1,5 @@
 use super::*;
-use crate::{ecs::*, MasterTable};
+use crate::{ecs::*, GameTile, MapTheme, MasterTable, ThemeTile};
 use std::collections::HashMap;
 
 mod load;
@@ -15,6 +15,9 @@ pub struct RawMaster {
     mob_index: HashMap<String, usize>,
     item_index: HashMap<String, usize>,
     prop_index: HashMap<String, usize>,
+    theme_index: HashMap<String, usize>,
+    prefab_index: HashMap<String, usize>,
+    spell_index: HashMap<String, usize>,
 }
 
 impl RawMaster {
@@ -23,7 +26,21 @@ impl RawMaster {
             mob_index: HashMap::new(),
             item_index: HashMap::new(),
             prop_index: HashMap::new(),
-            raws: Raws { items: Vec::new(), mobs: Vec::new(), props: Vec::new(), spawn_table: Vec::new() },
+            theme_index: HashMap::new(),
+            prefab_index: HashMap::new(),
+            spell_index: HashMap::new(),
+            raws: Raws {
+                items: Vec::new(),
+                mobs: Vec::new(),
+                props: Vec::new(),
+                tiles: Vec::new(),
+                hunger: RawHungerConfig::default(),
+                spells: Vec::new(),
+                themes: Vec::new(),
+                prefabs: Vec::new(),
+                prefab_legend: Vec::new(),
+                spawn_table: Vec::new(),
+            },
         }
     }
 }
@@ -57,6 +74,94 @@ pub fn get_renderable_component(glyph: &RawGlyph) -> crate::ecs::Glyph {
     }
 }
 
+pub fn get_light_component(light: &RawLight) -> LightSource {
+    LightSource::new(light.radius, RGB::from_hex(&light.color).expect("Invalid RGB"))
+}
+
+fn get_game_tile(tile: &RawTile) -> GameTile {
+    GameTile {
+        tile_type: tile.tile_type,
+        glyph: to_cp437(tile.glyph.chars().next().unwrap()),
+        color: ColorPair::new(RGB::from_hex(&tile.fg).expect("Invalid RGB"), RGB::named(BLACK)),
+        cost: tile.cost.unwrap_or(1.0),
+        opaque: tile.opaque.unwrap_or(false),
+        walkable: tile.walkable.unwrap_or(true),
+        swimmable: tile.swimmable.unwrap_or(false),
+        slows: tile.slows.unwrap_or(false),
+        extinguishes_fire: tile.extinguishes_fire.unwrap_or(false),
+        flammable: tile.flammable.unwrap_or(false),
+        damage: tile.damage.unwrap_or(0),
+    }
+}
+
+pub fn get_hunger_config(raws: &RawMaster) -> RawHungerConfig { raws.raws.hunger.clone() }
+
+pub fn get_hunger_stage(raws: &RawMaster, state: HungerState) -> Option<RawHungerStage> {
+    raws.raws.hunger.stages.iter().find(|stage| stage.state == state).cloned()
+}
+
+pub fn get_spell(raws: &RawMaster, name: &str) -> Option<RawSpell> {
+    raws.spell_index.get(name).map(|idx| raws.raws.spells[*idx].clone())
+}
+
+pub fn get_prefab(raws: &RawMaster, name: &str) -> Option<RawPrefab> {
+    raws.prefab_index.get(name).map(|idx| raws.raws.prefabs[*idx].clone())
+}
+
+/// The shared prefab legend, with a prefab's own overrides taking precedence
+pub fn get_prefab_legend(raws: &RawMaster, overrides: &HashMap<char, RawLegendEntry>) -> HashMap<char, RawLegendEntry> {
+    let mut legend = raws
+        .raws
+        .prefab_legend
+        .iter()
+        .filter_map(|entry| entry.glyph.chars().next().map(|glyph| (glyph, entry.clone())))
+        .collect::<HashMap<_, _>>();
+
+    legend.extend(overrides.iter().map(|(glyph, entry)| (*glyph, entry.clone())));
+    legend
+}
+
+pub fn get_prefabs_for_depth(raws: &RawMaster, kind: PrefabKind, depth: i32) -> Vec<RawPrefab> {
+    raws.raws
+        .prefabs
+        .iter()
+        .filter(|p| p.kind == kind && depth >= p.first_depth && depth <= p.last_depth)
+        .cloned()
+        .collect()
+}
+
+pub fn get_theme(raws: &RawMaster, name: &str) -> Option<MapTheme> {
+    let theme = &raws.raws.themes[*raws.theme_index.get(name)?];
+
+    let tiles = theme.tiles.iter().map(|(tt, tile)| (*tt, get_theme_tile(tile))).collect();
+    let fallback = theme.fallback.as_ref().map(get_theme_tile);
+
+    Some(MapTheme { name: theme.name.clone(), tiles, fallback })
+}
+
+/// Picks the first theme whose depth range covers `depth`, falling back to the "default" theme.
+pub fn get_theme_for_depth(raws: &RawMaster, depth: i32) -> MapTheme {
+    raws.raws
+        .themes
+        .iter()
+        .find(|t| match (t.min_depth, t.max_depth) {
+            (None, None) => false,
+            (min, max) => depth >= min.unwrap_or(i32::MIN) && depth <= max.unwrap_or(i32::MAX),
+        })
+        .and_then(|t| get_theme(raws, &t.name))
+        .or_else(|| get_theme(raws, "default"))
+        .unwrap_or_default()
+}
+
+fn get_theme_tile(tile: &RawThemeTile) -> ThemeTile {
+    ThemeTile {
+        wall_auto_tile: tile.wall_auto_tile.unwrap_or(false),
+        flammable: tile.flammable.unwrap_or(false),
+        glyph: tile.glyph.as_ref().map(|g| to_cp437(g.chars().next().unwrap())),
+        fg: tile.fg.as_ref().map(|fg| RGB::from_hex(fg).expect("Invalid RGB")),
+    }
+}
+
 fn find_slot_for_equippable_item(tag: &str, raws: &RawMaster) -> EquipmentSlot {
     if !raws.item_index.contains_key(tag) {
         panic!("Trying to equip an unknown item: {}", tag);
@@ -60,28 +60,29 @@ macro_rules! apply_effects {
         let effect_options = effect.1.as_ref().unwrap_or(&default);
             match effect_name {
                 AREA_OF_EFFECT => $eb.insert(AreaOfEffect::new(effect_options.parse::<i32>().unwrap())),
-                // CONFUSION => {
-                //     $eb.insert(Confusion{});
-                //     $eb.insert(Duration{ turns: effect.1.unwrap().parse::<i32>().unwrap() });
-                // }
+                CONFUSION => $eb.insert(Confusion::new(effect_options.parse::<i32>().unwrap())),
                 DAMAGE => $eb.insert(InflictsDamage(effect_options.parse::<i32>().unwrap())),
                 // "damage_over_time" => $eb = $eb.with( DamageOverTime { damage : effect.1.unwrap().parse::<i32>().unwrap() } ),
                 // "duration" => $eb = $eb.with(Duration { turns: effect.1.unwrap().parse::<i32>().unwrap() }),
-                FOOD => $eb.insert(ProvidesFood{}),
+                FLOOD => $eb.insert(SpawnsTileEffect::new(TileEffectKind::Water, effect_options.parse::<i32>().unwrap())),
+                FOOD => $eb.insert(ProvidesFood::new(effect_options.parse::<i32>().ok())),
+                IGNITE => $eb.insert(SpawnsTileEffect::new(TileEffectKind::Fire, effect_options.parse::<i32>().unwrap())),
                 // "identify" => $eb = $eb.with(ProvidesIdentification{}),
                 MAGIC_MAPPING => $eb.insert(MagicMapper{}),
                 PARTICLE => $eb.insert(parse_particle(effect_options)),
+                POISON_GAS => $eb.insert(SpawnsTileEffect::new(TileEffectKind::PoisonGas, effect_options.parse::<i32>().unwrap())),
                 PARTICLE_LINE => $eb.insert(parse_particle_line(effect_options)),
                 PROVIDES_HEALING => $eb.insert(ProvidesHealing(effect_options.parse::<i32>().unwrap())),
-                // "provides_mana" => $eb = $eb.with(ProvidesMana{ mana_amount: effect.1.unwrap().parse::<i32>().unwrap() }),
+                PROVIDES_MANA => $eb.insert(ProvidesMana(effect_options.parse::<i32>().unwrap())),
                 RANGED => $eb.insert(Ranged(effect_options.parse::<i32>().unwrap())),
                 // "remove_curse" => $eb = $eb.with(ProvidesRemoveCurse{}),
                 SINGLE_ACTIVATION => $eb.insert(SingleActivation{}),
+                SMOKE => $eb.insert(SpawnsTileEffect::new(TileEffectKind::Smoke, effect_options.parse::<i32>().unwrap())),
                 // "slow" => $eb = $eb.with(Slow{ initiative_penalty : effect.1.unwrap().parse::<f32>().unwrap() }),
                 // "target_self" => $eb = $eb.with( AlwaysTargetsSelf{} ),
-                // "teach_spell" => $eb = $eb.with(TeachesSpell{ spell: effect.1.unwrap().to_string() }),
+                TEACH_SPELL => $eb.insert(TeachesSpell::new(effect_options.clone())),
                 // "town_portal" => $eb = $eb.with(TownPortal{}),
-                _ => {println!("Warning: consumable effect {} not implemented.", effect_name); $eb}
+                _ => {log::warn!("Consumable effect {} not implemented.", effect_name); $eb}
             };
         }
     };
@@ -135,6 +136,7 @@ pub fn spawn_named_mob(
             AIType::Bystander => {
                 eb.insert(Bystander {});
             }
+            AIType::Idle => {}
         },
         None => {
             eb.insert(Monster {});
@@ -144,6 +146,31 @@ pub fn spawn_named_mob(
     if mob_template.blocks_tile {
         eb.insert(BlocksTile {});
     }
+    if let Some(true) = mob_template.opens_doors {
+        eb.insert(CanOpenDoors {});
+    }
+    if let Some(true) = mob_template.flying {
+        eb.insert(Flying {});
+    }
+    if let Some(true) = mob_template.swims {
+        eb.insert(CanSwim {});
+    }
+    if let Some(message) = &mob_template.recruitable {
+        eb.insert(Recruitable::new(message.clone()));
+    }
+    if let Some(gold) = &mob_template.gold {
+        eb.insert(Gold(crate::rng::roll_str(gold)));
+    }
+    if let Some(schedule) = &mob_template.schedule {
+        eb.insert(Schedule::new(schedule.clone()));
+    }
+    if let Some(barks) = &mob_template.barks {
+        eb.insert(Barks::new(barks.clone()));
+    }
+    if let Some(true) = mob_template.hungers {
+        let duration = get_hunger_stage(raws, HungerState::Normal).map_or(0, |stage| stage.duration);
+        eb.insert(HungerClock::new(HungerState::Normal, duration));
+    }
     eb.insert(CombatStats {
         max_hp: mob_template.stats.max_hp,
         hp: mob_template.stats.hp,
@@ -152,6 +179,10 @@ pub fn spawn_named_mob(
     });
     eb.insert(FieldOfView::new(mob_template.vision_range));
 
+    if let Some(light) = &mob_template.light {
+        eb.insert(get_light_component(light));
+    }
+
     Some(eb.id())
 }
 
@@ -181,15 +212,29 @@ pub fn spawn_named_prop(
     }
     // Door?
     if let Some(door_open) = prop_template.door_open { eb.insert(Door(door_open)); }
+    // Lock
+    if let Some(lock) = &prop_template.lock { eb.insert(Lock { key: lock.key.clone(), locked: lock.locked }); }
+    // Light Source
+    if let Some(light) = &prop_template.light { eb.insert(get_light_component(light)); }
     // Trigger Trait (Traps)
     if let Some(entry_trigger) = &prop_template.entry_trigger {
-        eb.insert(EntryTrigger {});
+        eb.insert(EntryTrigger { filters: entry_trigger.filters.clone().unwrap_or_default() });
         apply_effects!(entry_trigger.effects, &mut eb);
     }
 
     Some(eb.id())
 }
 
+/// Spawns the template entity for every spell, which casting later looks up by name
+pub fn spawn_all_spells(raws: &RawMaster, commands: &mut Commands) {
+    for spell in raws.raws.spells.iter() {
+        let mut eb = commands.spawn();
+        eb.insert(Naming(spell.name.clone()));
+        eb.insert(SpellTemplate::new(spell.mana_cost));
+        apply_effects!(spell.effects, &mut eb);
+    }
+}
+
 pub fn spawn_named_entity(commands: &mut Commands, key: &str, pos: SpawnType) -> Option<Entity> {
     let raws = RAWS.lock();
     if raws.item_index.contains_key(key) {
@@ -203,6 +248,7 @@ pub fn spawn_named_entity(commands: &mut Commands, key: &str, pos: SpawnType) ->
     None
 }
 
+#[derive(Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
 pub enum SpawnTableType {
     Item,
     Mob,
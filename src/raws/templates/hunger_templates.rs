@@ -0,0 +1,21 @@
+use super::*;
+use crate::ecs::HungerState;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct RawHungerConfig {
+    pub start_nutrition: i32,
+    pub default_nutrition: i32,
+    pub corpse_nutrition: i32,
+    pub starving_damage: i32,
+    pub starving_message: String,
+    pub stages: Vec<RawHungerStage>,
+}
+
+/// A stage of hunger that a clock moves into once the previous one runs out
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawHungerStage {
+    pub state: HungerState,
+    pub duration: i32,
+    pub message: String,
+    pub color: String,
+}
@@ -6,16 +6,27 @@ use super::*;
 pub enum AIType {
     Basic,
     Bystander,
+    /// Stands still until something happens to it, such as a prisoner waiting to be rescued
+    Idle,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawMob {
     pub name: String,
     pub blocks_tile: bool,
+    pub opens_doors: Option<bool>,
+    pub flying: Option<bool>,
+    pub swims: Option<bool>,
+    pub hungers: Option<bool>,
+    pub recruitable: Option<String>,
+    pub schedule: Option<Vec<ScheduleEntry>>,
+    pub barks: Option<Vec<String>>,
+    pub gold: Option<String>,
     pub vision_range: i32,
     pub ai: Option<AIType>,
     pub stats: RawMobStats,
     pub glyph: Option<RawGlyph>,
+    pub light: Option<RawLight>,
 }
 impl_raw!(RawMob);
 
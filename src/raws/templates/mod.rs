@@ -2,15 +2,25 @@ use super::*;
 use core::fmt::Debug;
 use std::any::Any;
 
+mod hunger_templates;
 mod item_templates;
 mod mob_templates;
+mod prefab_templates;
 mod prop_templates;
 mod spawn_table_templates;
+mod spell_templates;
+mod theme_templates;
+mod tile_templates;
 
+pub use hunger_templates::*;
 pub use item_templates::*;
 pub use mob_templates::*;
+pub use prefab_templates::*;
 pub use prop_templates::*;
 pub use spawn_table_templates::*;
+pub use spell_templates::*;
+pub use theme_templates::*;
+pub use tile_templates::*;
 
 pub trait BaseRawComponent: Debug + Clone {
     fn name(&self) -> String;
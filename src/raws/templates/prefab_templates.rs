@@ -0,0 +1,273 @@
+use super::*;
+use crate::TileType;
+use bracket_lib::prelude::{to_char, XpFile};
+
+#[derive(Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum PrefabKind {
+    Room,
+    Section,
+    Level,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum HorizontalPlacement {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum VerticalPlacement {
+    Top,
+    Center,
+    Bottom,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawPrefab {
+    pub name: String,
+    pub kind: PrefabKind,
+    pub width: usize,
+    pub height: usize,
+    pub first_depth: i32,
+    pub last_depth: i32,
+    /// How often the prefab is picked compared to the others, higher is more common
+    pub weight: i32,
+    pub placement: Option<(HorizontalPlacement, VerticalPlacement)>,
+    pub rotate: bool,
+    pub mirror: bool,
+    pub tiles: Vec<char>,
+    pub legend: HashMap<char, RawLegendEntry>,
+}
+
+/// What a prefab character turns into: a tile, a named spawn, a roll on the spawn table, or a mix.
+/// Spawns without a tile are placed on floor.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct RawLegendEntry {
+    pub glyph: String,
+    pub tile: Option<TileType>,
+    pub spawn: Option<String>,
+    pub table: Option<SpawnTableType>,
+    pub start: Option<bool>,
+}
+
+impl RawPrefab {
+    /// Parses a prefab file: `key: value` metadata lines, a `---` line, then the map rows.
+    /// Prefabs drawn in REXPaint name their `.xp` file with a `rex` key instead of having rows.
+    /// Any number of `legend: k = Kobold, tile:Grass` lines override the shared legend for this prefab.
+    pub fn parse(source: &str, rex_files: &[(&str, &[u8])]) -> Result<RawPrefab, String> {
+        let mut metadata: HashMap<String, String> = HashMap::new();
+        let mut legend: HashMap<char, RawLegendEntry> = HashMap::new();
+        let mut rows: Vec<Vec<char>> = Vec::new();
+        let mut in_body = false;
+
+        for line in source.lines() {
+            let line = line.trim_end_matches('\r');
+            if in_body {
+                rows.push(line.chars().map(|c| if c == '\u{a0}' { ' ' } else { c }).collect());
+            } else if line.trim() == "---" {
+                in_body = true;
+            } else if let Some((key, value)) = line.split_once(':') {
+                if key.trim().eq_ignore_ascii_case("legend") {
+                    let (glyph, entry) =
+                        parse_legend_override(value).ok_or(format!("Bad legend line '{}'", line))?;
+                    legend.insert(glyph, entry);
+                } else {
+                    metadata.insert(key.trim().to_lowercase(), value.trim().to_string());
+                }
+            } else if !line.trim().is_empty() {
+                return Err(format!("Bad header line '{}'", line));
+            }
+        }
+
+        let name = metadata.get("name").ok_or("Missing name")?.clone();
+        let kind = match metadata.get("type").map(|t| t.to_lowercase()).as_deref() {
+            Some("room") => PrefabKind::Room,
+            Some("section") => PrefabKind::Section,
+            Some("level") => PrefabKind::Level,
+            _ => return Err(format!("{}: type must be room, section or level", name)),
+        };
+
+        let (width, height) = metadata
+            .get("size")
+            .and_then(|size| size.split_once('x'))
+            .and_then(|(w, h)| Some((w.trim().parse::<usize>().ok()?, h.trim().parse::<usize>().ok()?)))
+            .ok_or(format!("{}: size must look like 12x10", name))?;
+
+        let (first_depth, last_depth) = match metadata.get("depth") {
+            None => (0, i32::MAX),
+            Some(depth) => depth
+                .split_once('-')
+                .and_then(|(a, b)| Some((a.trim().parse::<i32>().ok()?, b.trim().parse::<i32>().ok()?)))
+                .ok_or(format!("{}: depth must look like 0-100", name))?,
+        };
+
+        let weight = match metadata.get("weight") {
+            None => 1,
+            Some(weight) => weight.parse::<i32>().map_err(|_| format!("{}: bad weight", name))?,
+        };
+
+        let placement = match metadata.get("placement") {
+            None if kind == PrefabKind::Section => return Err(format!("{}: sections need a placement", name)),
+            None => None,
+            Some(placement) => Some(parse_placement(placement).ok_or(format!("{}: bad placement", name))?),
+        };
+
+        let rotate = metadata.get("rotate").map(|r| r == "true").unwrap_or(false);
+        let mirror = metadata.get("mirror").map(|m| m == "true").unwrap_or(false);
+
+        if let Some(rex) = metadata.get("rex") {
+            let (_, bytes) = rex_files
+                .iter()
+                .find(|(path, _)| *path == rex.as_str())
+                .ok_or(format!("{}: unknown REXPaint file {}", name, rex))?;
+            rows = read_rex_rows(bytes).map_err(|e| format!("{}: {}", name, e))?;
+        }
+
+        // Validate the map against the declared size
+        if rows.len() != height {
+            return Err(format!("{}: expected {} rows, found {}", name, height, rows.len()));
+        }
+
+        let mut tiles = Vec::with_capacity(width * height);
+        for (y, row) in rows.iter_mut().enumerate() {
+            if row.len() > width {
+                return Err(format!("{}: row {} is {} wide, expected {}", name, y, row.len(), width));
+            }
+
+            // Editors like to strip trailing spaces, so short rows are padded with floor
+            row.resize(width, ' ');
+            tiles.extend(row.iter());
+        }
+
+        Ok(RawPrefab {
+            name,
+            kind,
+            width,
+            height,
+            first_depth,
+            last_depth,
+            weight,
+            placement,
+            rotate,
+            mirror,
+            tiles,
+            legend,
+        })
+    }
+}
+
+fn parse_legend_override(value: &str) -> Option<(char, RawLegendEntry)> {
+    let (glyph, parts) = value.split_once('=')?;
+    let glyph = glyph.trim().chars().next()?;
+
+    let mut entry = RawLegendEntry { glyph: glyph.to_string(), ..Default::default() };
+    for part in parts.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        match part.split_once(':') {
+            Some(("tile", tile)) => entry.tile = Some(ron::from_str::<TileType>(tile.trim()).ok()?),
+            Some(("table", table)) => entry.table = Some(ron::from_str::<SpawnTableType>(table.trim()).ok()?),
+            _ if part == "start" => entry.start = Some(true),
+            _ => entry.spawn = Some(part.to_string()),
+        }
+    }
+
+    Some((glyph, entry))
+}
+
+fn parse_placement(placement: &str) -> Option<(HorizontalPlacement, VerticalPlacement)> {
+    let placement = placement.to_lowercase();
+    let (horizontal, vertical) = placement.split_once(' ')?;
+
+    let horizontal = match horizontal.trim() {
+        "left" => HorizontalPlacement::Left,
+        "center" => HorizontalPlacement::Center,
+        "right" => HorizontalPlacement::Right,
+        _ => return None,
+    };
+
+    let vertical = match vertical.trim() {
+        "top" => VerticalPlacement::Top,
+        "center" => VerticalPlacement::Center,
+        "bottom" => VerticalPlacement::Bottom,
+        _ => return None,
+    };
+
+    Some((horizontal, vertical))
+}
+
+fn read_rex_rows(bytes: &[u8]) -> Result<Vec<Vec<char>>, String> {
+    let xp_file = XpFile::read(&mut &bytes[..]).map_err(|e| e.to_string())?;
+    let layer = xp_file.layers.first().ok_or("REXPaint file has no layers")?;
+
+    let mut rows = vec![Vec::with_capacity(layer.width); layer.height];
+    for (y, row) in rows.iter_mut().enumerate() {
+        for x in 0..layer.width {
+            let cell = layer.get(x, y).ok_or("REXPaint cell out of bounds")?;
+            row.push(to_char(cell.ch as u8));
+        }
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: &str = "name: Camp\ntype: section\nsize: 4x2\ndepth: 3-7\nweight: 5\n\
+                           placement: left bottom\nrotate: true\n---\n#..#\n#\n";
+
+    #[test]
+    fn parses_header() {
+        let prefab = RawPrefab::parse(SECTION, &[]).unwrap();
+        assert_eq!(prefab.name, "Camp");
+        assert_eq!(prefab.kind, PrefabKind::Section);
+        assert_eq!((prefab.width, prefab.height), (4, 2));
+        assert_eq!((prefab.first_depth, prefab.last_depth), (3, 7));
+        assert_eq!(prefab.weight, 5);
+        assert_eq!(prefab.placement, Some((HorizontalPlacement::Left, VerticalPlacement::Bottom)));
+        assert!(prefab.rotate);
+        assert!(!prefab.mirror);
+    }
+
+    #[test]
+    fn pads_short_rows() {
+        let prefab = RawPrefab::parse(SECTION, &[]).unwrap();
+        assert_eq!(prefab.tiles, "#..##   ".chars().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn defaults_optional_header_lines() {
+        let prefab = RawPrefab::parse("name: Plain\ntype: room\nsize: 1x1\n---\n.\n", &[]).unwrap();
+        assert_eq!((prefab.first_depth, prefab.last_depth), (0, i32::MAX));
+        assert_eq!(prefab.weight, 1);
+        assert_eq!(prefab.placement, None);
+    }
+
+    #[test]
+    fn parses_legend_overrides() {
+        let source = "name: A\ntype: room\nsize: 1x1\nlegend: k = Kobold, tile:Grass\n---\nk\n";
+        let prefab = RawPrefab::parse(source, &[]).unwrap();
+        let entry = &prefab.legend[&'k'];
+        assert_eq!(entry.spawn.as_deref(), Some("Kobold"));
+        assert_eq!(entry.tile, Some(TileType::Grass));
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: 1x1\nlegend: k\n---\nk\n", &[]).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert!(RawPrefab::parse("type: room\nsize: 1x1\n---\n.\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: vault\nsize: 1x1\n---\n.\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: big\n---\n.\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: section\nsize: 1x1\n---\n.\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: 1x1\nnot a header\n---\n.\n", &[]).is_err());
+    }
+
+    #[test]
+    fn rejects_size_mismatches() {
+        // Too many rows, too few rows and a row wider than declared
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: 2x1\n---\n..\n..\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: 2x3\n---\n..\n..\n", &[]).is_err());
+        assert!(RawPrefab::parse("name: A\ntype: room\nsize: 2x2\n---\n..\n...\n", &[]).is_err());
+    }
+}
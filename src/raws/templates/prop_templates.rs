@@ -1,4 +1,4 @@
-use crate::impl_raw;
+use crate::{impl_raw, TriggerFilter};
 
 use super::*;
 use std::collections::HashMap;
@@ -8,15 +8,30 @@ pub struct RawProp {
     pub name: String,
     pub hidden: Option<bool>,
     pub door_open: Option<bool>,
+    pub lock: Option<RawLock>,
     pub glyph: Option<RawGlyph>,
     pub blocks_tile: Option<bool>,
     pub blocks_visibility: Option<bool>,
     pub entry_trigger: Option<RawEntryTrigger>,
+    pub light: Option<RawLight>,
 }
 
 impl_raw!(RawProp);
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawEntryTrigger {
+    pub filters: Option<Vec<TriggerFilter>>,
     pub effects: HashMap<String, Option<String>>,
 }
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawLight {
+    pub radius: i32,
+    pub color: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawLock {
+    pub key: String,
+    pub locked: bool,
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+/// A spell's effects use the same keys as consumable items
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawSpell {
+    pub name: String,
+    pub mana_cost: i32,
+    pub effects: HashMap<String, Option<String>>,
+}
@@ -0,0 +1,19 @@
+use super::*;
+use crate::TileType;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawTheme {
+    pub name: String,
+    pub min_depth: Option<i32>,
+    pub max_depth: Option<i32>,
+    pub fallback: Option<RawThemeTile>,
+    pub tiles: HashMap<TileType, RawThemeTile>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawThemeTile {
+    pub fg: Option<String>,
+    pub glyph: Option<String>,
+    pub wall_auto_tile: Option<bool>,
+    pub flammable: Option<bool>,
+}
@@ -0,0 +1,17 @@
+use super::*;
+use crate::TileType;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawTile {
+    pub tile_type: TileType,
+    pub glyph: String,
+    pub fg: String,
+    pub cost: Option<f32>,
+    pub opaque: Option<bool>,
+    pub walkable: Option<bool>,
+    pub swimmable: Option<bool>,
+    pub slows: Option<bool>,
+    pub extinguishes_fire: Option<bool>,
+    pub flammable: Option<bool>,
+    pub damage: Option<i32>,
+}
@@ -1,10 +1,12 @@
 use super::*;
 
+mod stats_panel;
+pub use stats_panel::*;
+
 ////////////////////////////////////////////////////////////////////////////////
 
 // Stats Panel
 pub const STATS_PANEL_WIDTH: i32 = 30;
-pub const STATS_PANEL_HEIGHT: i32 = 8;
 
 // Map Panel
 pub const MAP_PANEL_WIDTH: i32 = UI_WIDTH - STATS_PANEL_WIDTH - 1;
@@ -81,32 +83,6 @@ pub fn labels(draw_batch: &mut DrawBatch, world: &World) {
     );
 }
 
-fn draw_stats(draw_batch: &mut DrawBatch, world: &mut World) {
-    let mut stats_q = world.query_filtered::<&CombatStats, With<Player>>();
-    for stats in stats_q.iter(world) {
-        let health = format!("Health: {}/{}", stats.hp, stats.max_hp);
-        let mana = format!("Mana:   {}/{}", 30, 30);
-        let xp = format!("Level:  {}", 1);
-
-        let text_x = STATS_PANEL.x1 + 1;
-        let bar_x = text_x + 14;
-
-        draw_batch.print_color(Point::new(text_x, 1), &health, ColorPair::new(WHITE, BLACK));
-        draw_batch.print_color(Point::new(text_x, 2), &mana, ColorPair::new(WHITE, BLACK));
-        draw_batch.print_color(Point::new(text_x, 3), &xp, ColorPair::new(WHITE, BLACK));
-
-        draw_batch.bar_horizontal(
-            Point::new(bar_x, 1),
-            14,
-            stats.hp,
-            stats.max_hp,
-            ColorPair::new(RED, BLACK),
-        );
-        draw_batch.bar_horizontal(Point::new(bar_x, 2), 14, 15, 30, ColorPair::new(NAVYBLUE, BLACK));
-        draw_batch.bar_horizontal(Point::new(bar_x, 3), 14, 100, 1000, ColorPair::new(GOLD, BLACK));
-    }
-}
-
 fn equipped(draw_batch: &mut DrawBatch, world: &mut World) -> i32 {
     let mut equipped_q = world.query::<(&Equipped, &Naming, Option<&MeleePowerBonus>)>();
     if let Some(player_entity) = world.get_resource::<Entity>() {
@@ -143,27 +119,29 @@ fn equipped(draw_batch: &mut DrawBatch, world: &mut World) -> i32 {
     }
 }
 
-fn status(draw_batch: &mut DrawBatch, world: &mut World) {
-    if let Some(player) = world.get_resource::<Entity>() {
-        if let Some(hc) = world.get::<HungerClock>(*player) {
-            let x = EQUIPMENT_PANEL.x1 + 1;
-            let y = EQUIPMENT_PANEL.y2 - 1;
-            match hc.state {
-                HungerState::Normal => {}
-                HungerState::WellFed => {
-                    draw_batch.print_color(Point::new(x, y), "Well Fed", ColorPair::new(GREEN, BLACK));
-                    // y -= 1;
-                }
-                HungerState::Hungry => {
-                    draw_batch.print_color(Point::new(x, y), "Hungry", ColorPair::new(ORANGE, BLACK));
-                    // y -= 1;
-                }
-                HungerState::Starving => {
-                    draw_batch.print_color(Point::new(x, y), "Starving", ColorPair::new(RED, BLACK));
-                    // y -= 1;
-                }
-            }
-        }
+/// Lists the player's companions at the bottom of the equipment panel
+fn party(draw_batch: &mut DrawBatch, world: &mut World) {
+    let mut party_q = world.query::<(&Naming, &CombatStats, &Companion)>();
+    let members = party_q.iter(world).collect::<Vec<_>>();
+    if members.is_empty() {
+        return;
+    }
+
+    let x = EQUIPMENT_PANEL.x1 + 1;
+    let mut y = EQUIPMENT_PANEL.y2 - members.len() as i32 - 1;
+    print_label(draw_batch, "Party", Point::new(MAP_PANEL.x2, y), STATS_PANEL.width(), WHITE, WHITE);
+
+    for (name, stats, companion) in members {
+        y += 1;
+        let order = match companion.order {
+            CompanionOrder::Follow => 'F',
+            CompanionOrder::Stay => 'S',
+            CompanionOrder::Attack(_) => 'A',
+        };
+
+        let label = format!("{:.11} [{}]", name.0, order);
+        draw_batch.print_color(Point::new(x, y), &label, ColorPair::new(WHITE, BLACK));
+        draw_batch.bar_horizontal(Point::new(x + 15, y), 12, stats.hp, stats.max_hp, ColorPair::new(RED, BLACK));
     }
 }
 
@@ -175,7 +153,7 @@ pub fn render_ui(world: &mut World) {
     labels(&mut gui_batch, world);
     draw_stats(&mut gui_batch, world);
     equipped(&mut gui_batch, world);
-    status(&mut gui_batch, world);
+    party(&mut gui_batch, world);
     bo_logging::print_log(&mut gui_batch, *LOG_PANEL);
 
     gui_batch.submit(BATCH_UI).expect("Batch error"); // On top of everything
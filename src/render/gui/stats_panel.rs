@@ -0,0 +1,196 @@
+use super::*;
+
+/// Lines kept free under the stats for status effects
+const STATUS_LINES: i32 = 2;
+/// Bars start after this many columns of text
+const STAT_TEXT_WIDTH: i32 = 14;
+const STAT_BAR_WIDTH: i32 = 14;
+
+/// The panel grows with the rows defined in `STAT_ROWS`
+pub const STATS_PANEL_HEIGHT: i32 = STAT_ROWS.len() as i32 + STATUS_LINES + 1;
+
+/// Everything the stats panel shows, read from the player's components once per frame
+#[derive(Debug, Default)]
+pub struct PlayerStats {
+    pub hp: i32,
+    pub max_hp: i32,
+    pub mana: i32,
+    pub max_mana: i32,
+    pub level: i32,
+    pub xp: i32,
+    pub xp_to_next_level: i32,
+    pub power: i32,
+    pub armor: i32,
+    pub gold: i32,
+    pub depth: i32,
+    pub turns: i32,
+    /// Hunger state, turns left in it and how long the state lasts
+    pub hunger: Option<(HungerState, i32, i32)>,
+    pub statuses: Vec<(&'static str, (u8, u8, u8))>,
+}
+
+pub struct StatBar {
+    label: String,
+    current: i32,
+    max: i32,
+    color: (u8, u8, u8),
+}
+
+impl_new!(StatBar, label: String, current: i32, max: i32, color: (u8, u8, u8));
+
+/// One line of the stats panel
+pub enum StatRow {
+    /// A label next to a bar showing how full something is
+    Bar(fn(&PlayerStats) -> StatBar),
+    /// Two short values side by side
+    Pair(fn(&PlayerStats) -> String, fn(&PlayerStats) -> String),
+    Text(fn(&PlayerStats) -> String),
+}
+
+/// The stats panel from top to bottom
+pub const STAT_ROWS: &[StatRow] = &[
+    StatRow::Bar(|s| StatBar::new(format!("Health: {}/{}", s.hp, s.max_hp), s.hp, s.max_hp, RED)),
+    StatRow::Bar(|s| {
+        StatBar::new(format!("Mana:   {}/{}", s.mana, s.max_mana), s.mana, s.max_mana, NAVYBLUE)
+    }),
+    StatRow::Bar(|s| StatBar::new(format!("Level:  {}", s.level), s.xp, s.xp_to_next_level, GOLD)),
+    StatRow::Bar(hunger_bar),
+    StatRow::Pair(|s| format!("Power: {}", s.power), |s| format!("Armor: {}", s.armor)),
+    StatRow::Pair(|s| format!("Gold:  {}", s.gold), |s| format!("Depth: {}", s.depth)),
+    StatRow::Text(|s| format!("Turn:  {}", s.turns)),
+];
+
+fn hunger_bar(stats: &PlayerStats) -> StatBar {
+    match stats.hunger {
+        None => StatBar::new(String::new(), 0, 0, BLACK),
+        Some((state, duration, max)) => {
+            let (label, color) = match state {
+                HungerState::WellFed => ("Well Fed", GREEN),
+                HungerState::Normal => ("Fed", WHITE),
+                HungerState::Hungry => ("Hungry", ORANGE),
+                HungerState::Starving => ("Starving", RED),
+            };
+
+            // The bar shows how long until the next stage
+            StatBar::new(label.to_string(), duration, max, color)
+        }
+    }
+}
+
+pub fn gather_player_stats(world: &mut World) -> Option<PlayerStats> {
+    let player = *world.get_resource::<Entity>()?;
+
+    let (mut power_bonus, mut armor_bonus) = (0, 0);
+    let mut equipped_q = world.query::<(&Equipped, Option<&MeleePowerBonus>, Option<&DefenseBonus>)>();
+    for (_, melee, defense) in equipped_q.iter(world).filter(|(equipped, _, _)| equipped.owner == player) {
+        power_bonus += melee.map_or(0, |m| m.power);
+        armor_bonus += defense.map_or(0, |d| d.defense);
+    }
+
+    let combat = world.get::<CombatStats>(player)?;
+    let mut stats = PlayerStats {
+        hp: combat.hp,
+        max_hp: combat.max_hp,
+        power: combat.power + power_bonus,
+        armor: combat.defense + armor_bonus,
+        depth: world.resource::<Map>().depth,
+        turns: bo_logging::get_event_count(TURN_DONE_EVENT),
+        ..Default::default()
+    };
+
+    if let Some(mana) = world.get::<Mana>(player) {
+        stats.mana = mana.current;
+        stats.max_mana = mana.max;
+    }
+    if let Some(experience) = world.get::<Experience>(player) {
+        stats.level = experience.level;
+        stats.xp = experience.xp;
+        stats.xp_to_next_level = experience.xp_to_next_level();
+    }
+    if let Some(gold) = world.get::<Gold>(player) {
+        stats.gold = gold.0;
+    }
+    if let Some(hunger) = world.get::<HungerClock>(player) {
+        let stage = raws::get_hunger_stage(&RAWS.lock(), hunger.state);
+        let max = stage.map_or(hunger.duration, |s| s.duration.max(hunger.duration)).max(1);
+        stats.hunger = Some((hunger.state, hunger.duration, max));
+    }
+
+    // Status effects
+    if world.get::<Confusion>(player).is_some() {
+        stats.statuses.push(("Confused", MAGENTA));
+    }
+    if world.get::<Sick>(player).is_some() {
+        stats.statuses.push(("Sick", ORANGE));
+    }
+    if world.get::<Slowed>(player).is_some() {
+        stats.statuses.push(("Slowed", CYAN));
+    }
+    if let Some(pos) = world.get::<Point>(player) {
+        let map = world.resource::<Map>();
+        match map.tile_effect(map.point2d_to_index(*pos)) {
+            Some(TileEffectKind::Fire) => stats.statuses.push(("Burning", RED)),
+            Some(TileEffectKind::PoisonGas) => stats.statuses.push(("Poisoned", GREEN)),
+            Some(TileEffectKind::Smoke) => stats.statuses.push(("In Smoke", GRAY)),
+            Some(TileEffectKind::Water) => stats.statuses.push(("Wet", LIGHT_BLUE)),
+            None => {}
+        }
+    }
+
+    Some(stats)
+}
+
+pub fn draw_stats(draw_batch: &mut DrawBatch, world: &mut World) {
+    let stats = match gather_player_stats(world) {
+        Some(stats) => stats,
+        None => return,
+    };
+
+    let text_x = STATS_PANEL.x1 + 1;
+    let bar_x = text_x + STAT_TEXT_WIDTH;
+    let second_column_x = text_x + STATS_PANEL_WIDTH / 2;
+
+    let mut y = STATS_PANEL.y1 + 1;
+    for row in STAT_ROWS {
+        match row {
+            StatRow::Bar(bar) => {
+                let bar = bar(&stats);
+                draw_batch.print_color(Point::new(text_x, y), &bar.label, ColorPair::new(WHITE, BLACK));
+                if bar.max > 0 {
+                    draw_batch.bar_horizontal(
+                        Point::new(bar_x, y),
+                        STAT_BAR_WIDTH,
+                        bar.current,
+                        bar.max,
+                        ColorPair::new(bar.color, BLACK),
+                    );
+                }
+            }
+            StatRow::Pair(left, right) => {
+                let color = ColorPair::new(WHITE, BLACK);
+                draw_batch.print_color(Point::new(text_x, y), left(&stats), color);
+                draw_batch.print_color(Point::new(second_column_x, y), right(&stats), color);
+            }
+            StatRow::Text(text) => {
+                draw_batch.print_color(Point::new(text_x, y), text(&stats), ColorPair::new(WHITE, BLACK));
+            }
+        }
+        y += 1;
+    }
+
+    // Status effects flow left to right, wrapping onto the lines kept for them
+    let mut x = text_x;
+    let last_y = y + STATUS_LINES - 1;
+    for (status, color) in stats.statuses.iter() {
+        if x + status.len() as i32 > STATS_PANEL.x2 - 1 {
+            if y == last_y {
+                break;
+            }
+            x = text_x;
+            y += 1;
+        }
+
+        draw_batch.print_color(Point::new(x, y), status, ColorPair::new(*color, BLACK));
+        x += status.len() as i32 + 1;
+    }
+}
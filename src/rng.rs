@@ -8,6 +8,12 @@ lazy_static! {
 
 pub fn reseed(seed: u64) { *RNG.lock() = RandomNumberGenerator::seeded(seed); }
 
+/// A fresh seed for a new run
+pub fn new_seed() -> u64 { RandomNumberGenerator::new().next_u64() }
+
 pub fn roll_dice(n: i32, die_type: i32) -> i32 { RNG.lock().roll_dice(n, die_type) }
 
 pub fn range(min: i32, max: i32) -> i32 { RNG.lock().range(min, max) }
+
+/// Rolls dice written like "2d6+1", giving 0 if the string can't be parsed
+pub fn roll_str(dice: &str) -> i32 { RNG.lock().roll_str(dice).unwrap_or(0) }
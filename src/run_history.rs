@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+#[cfg(target_os = "emscripten")]
+pub const HISTORY_FILENAME: &str = "/bload_oath/run_history.ron";
+
+#[cfg(not(target_os = "emscripten"))]
+pub const HISTORY_FILENAME: &str = "run_history.ron";
+
+/// Only this many of the best runs are kept
+const MAX_HISTORY: usize = 100;
+
+const SCORE_PER_DEPTH: i32 = 100;
+const SCORE_PER_KILL: i32 = 10;
+const SCORE_PER_GOLD: i32 = 1;
+
+/// One finished run, as kept in the history file
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunRecord {
+    pub name: String,
+    pub seed: u64,
+    pub score: i32,
+    pub depth: i32,
+    pub turns: i32,
+    pub kills: i32,
+    pub gold: i32,
+    pub cause_of_death: String,
+    /// Seconds since the unix epoch when the run ended
+    pub timestamp: u64,
+}
+
+impl RunRecord {
+    /// The day the run ended, as YYYY-MM-DD
+    pub fn date(&self) -> String {
+        // Days since 1970-01-01 to a civil date, after Howard Hinnant's `civil_from_days`
+        let days = (self.timestamp / 86_400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// Seconds since the unix epoch. The browser build has no clock to ask, so its runs all date from 1970.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_timestamp() -> u64 {
+    std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |time| time.as_secs())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn run_timestamp() -> u64 { 0 }
+
+pub fn score_run(depth: i32, kills: i32, gold: i32) -> i32 {
+    depth * SCORE_PER_DEPTH + kills * SCORE_PER_KILL + gold * SCORE_PER_GOLD
+}
+
+/// Every run in the history file, best score first
+pub fn high_scores() -> Vec<RunRecord> {
+    let mut history = load_run_history();
+    sort_by_score(&mut history);
+    history
+}
+
+/// Best score first, newer runs ahead on a tie
+fn sort_by_score(history: &mut [RunRecord]) {
+    history.sort_by(|a, b| b.score.cmp(&a.score).then(b.timestamp.cmp(&a.timestamp)));
+}
+
+/// Adds a run to the history file, returning where it placed in the high scores.
+/// The lowest scores drop off once the history is full.
+pub fn record_run(record: RunRecord) -> Result<usize, BoxedError> {
+    let mut history = high_scores();
+    let place = history.iter().filter(|r| r.score > record.score).count() + 1;
+
+    history.push(record);
+    sort_by_score(&mut history);
+    history.truncate(MAX_HISTORY);
+    save_run_history(&history)?;
+
+    Ok(place)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load_run_history() -> Vec<RunRecord> { Vec::new() }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_run_history() -> Vec<RunRecord> {
+    let contents = match std::fs::read_to_string(HISTORY_FILENAME) {
+        Ok(contents) => contents,
+        Err(_) => return Vec::new(),
+    };
+
+    ron::from_str(&contents).unwrap_or_else(|e| {
+        log::warn!("run_history::load_run_history: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_run_history(_history: &[RunRecord]) -> Result<(), BoxedError> { Ok(()) }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_run_history(history: &[RunRecord]) -> Result<(), BoxedError> {
+    let contents = ron::ser::to_string_pretty(history, ron::ser::PrettyConfig::new())?;
+    std::fs::write(HISTORY_FILENAME, contents)?;
+    Ok(())
+}
@@ -17,7 +17,7 @@ pub struct SerializationHelper {
 #[derive(Component, Serialize, Deserialize, Clone)]
 pub struct DMSerializationHelper {
     pub map: MasterDungeonMap,
-    pub log: Vec<Vec<LogFragment>>,
+    pub log: Vec<LogEntry>,
     pub events: HashMap<String, i32>,
 }
 
@@ -25,6 +25,6 @@ impl_new!(SerializationHelper, map: Map);
 impl_new!(
     DMSerializationHelper,
     map: MasterDungeonMap,
-    log: Vec<Vec<LogFragment>>,
+    log: Vec<LogEntry>,
     events: HashMap<String, i32>
 );
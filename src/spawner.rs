@@ -7,8 +7,12 @@ use std::collections::HashMap;
 
 pub fn spawn_player(mut commands: Commands, map_builder: Res<BuilderMap>) {
     let start_pos = map_builder.starting_position.unwrap();
+    let starting_spell = raws::get_spell(&RAWS.lock(), MAGIC_MISSILE)
+        .map(|spell| KnownSpell::new(spell.name, spell.mana_cost))
+        .into_iter()
+        .collect::<Vec<_>>();
 
-    println!("Starting position: {:?}", start_pos);
+    log::debug!("Starting position: {:?}", start_pos);
     // Spawn Player
     let player = commands
         .spawn()
@@ -23,7 +27,14 @@ pub fn spawn_player(mut commands: Commands, map_builder: Res<BuilderMap>) {
         .insert(Naming("Danny".to_string()))
         .insert(Description::new("A curious farm boy."))
         .insert(Blood(DARK_RED.into()))
-        .insert(HungerClock::new(HungerState::WellFed, 20))
+        .insert(HungerClock::new(HungerState::WellFed, raws::get_hunger_config(&RAWS.lock()).start_nutrition))
+        .insert(LightSource::new(5, RGB::from_f32(1.0, 1.0, 0.6)))
+        .insert(CanOpenDoors {})
+        .insert(CanSwim {})
+        .insert(Mana::new(30))
+        .insert(Experience::new())
+        .insert(Gold(0))
+        .insert(KnownSpells::new(starting_spell))
         .id();
 
     commands.insert_resource(player);
@@ -47,6 +58,8 @@ pub fn spawn_player(mut commands: Commands, map_builder: Res<BuilderMap>) {
         .insert(InBackpack::new(player));
 }
 
+pub fn spawn_spells(mut commands: Commands) { raws::spawn_all_spells(&RAWS.lock(), &mut commands); }
+
 pub fn spawn_entities(mut commands: Commands, map_builder: Res<BuilderMap>) {
     for entity in map_builder.spawn_list.iter() {
         spawner::spawn_entity(&mut commands, &map_builder.map, &(&entity.0, &entity.1));
@@ -122,7 +135,7 @@ pub fn spawn_entity(commands: &mut Commands, map: &Map, spawn: &(&usize, &String
         return;
     }
 
-    println!("WARNING: We don't know how to spawn [{}]!", spawn.1);
+    log::warn!("We don't know how to spawn [{}]!", spawn.1);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -133,6 +146,7 @@ impl Plugin for SpawnerPlugin {
             AppState::NewGame,
             SystemSet::new()
                 .with_system(spawn_player)
+                .with_system(spawn_spells)
                 .with_system(spawn_entities.after(spawn_player))
                 .with_system(switch_in_game_state!(AppState::Playing)),
         );
@@ -14,12 +14,19 @@ pub const PARISHIONER: &str = "Parishioner";
 pub const DOCK_WORKER: &str = "Dock Worker";
 pub const SHADY_SALESMAN: &str = "Shady Salesman";
 pub const WANNABE_PIRATE: &str = "Wannabe Pirate";
+pub const SELLSWORD: &str = "Sellsword";
 pub const MAGIC_MAPPING_SCROLL: &str = "Magic Mapping Scroll";
+pub const VAULT_KEY: &str = "Vault Key";
+pub const RATIONS: &str = "Rations";
+
+// Spells
+pub const MAGIC_MISSILE: &str = "Magic Missile";
 
 // Props
 pub const BED: &str = "Bed";
 pub const KEG: &str = "Keg";
 pub const DOOR: &str = "Door";
+pub const VAULT_DOOR: &str = "Vault Door";
 pub const LOOM: &str = "Loom";
 pub const TABLE: &str = "Table";
 pub const ANVIL: &str = "Anvil";
@@ -43,6 +50,11 @@ pub const CONFUSION: &str = "confusion";
 pub const FOOD: &str = "food";
 pub const PROVIDES_HEALING: &str = "provides_healing";
 pub const PROVIDES_MANA: &str = "provides_mana";
+pub const TEACH_SPELL: &str = "teach_spell";
 pub const MAGIC_MAPPING: &str = "magic_mapping";
 pub const PARTICLE: &str = "particle";
 pub const PARTICLE_LINE: &str = "particle_line";
+pub const IGNITE: &str = "ignite";
+pub const POISON_GAS: &str = "poison_gas";
+pub const SMOKE: &str = "smoke";
+pub const FLOOD: &str = "flood";